@@ -0,0 +1,130 @@
+use c_kzg::*;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::path::Path;
+
+fn generate_field_element(rng: &mut StdRng) -> Bytes32 {
+    let mut bytes = [0u8; BYTES_PER_FIELD_ELEMENT];
+    rng.fill(&mut bytes[..]);
+    bytes[0] = 0;
+    Bytes32::new(bytes)
+}
+
+fn generate_blob(rng: &mut StdRng) -> Blob {
+    let mut bytes = [0u8; BYTES_PER_BLOB];
+    rng.fill(&mut bytes[..]);
+    for i in 0..FIELD_ELEMENTS_PER_BLOB {
+        bytes[i * BYTES_PER_FIELD_ELEMENT] = 0;
+    }
+    Blob::new(bytes)
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    // A fixed seed keeps the inputs identical across runs.
+    let mut rng = StdRng::seed_from_u64(0);
+    let trusted_setup_file = Path::new("src/trusted_setup.txt");
+    assert!(trusted_setup_file.exists());
+    let kzg_settings = KzgSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+    let blobs: Vec<Blob> = (0..MAX_BLOBS_PER_BLOCK)
+        .map(|_| generate_blob(&mut rng))
+        .collect();
+    let commitments = kzg_settings.blobs_to_kzg_commitments(&blobs).unwrap();
+    let proofs: Vec<KzgProof> = blobs
+        .iter()
+        .zip(&commitments)
+        .map(|(blob, commitment)| {
+            kzg_settings
+                .compute_blob_kzg_proof(blob, commitment)
+                .unwrap()
+        })
+        .collect();
+    let z = generate_field_element(&mut rng);
+    let proof_with_eval = kzg_settings.compute_kzg_proof(&blobs[0], &z).unwrap();
+
+    let mut group = c.benchmark_group("load_trusted_setup");
+    group.bench_function("file", |b| {
+        b.iter(|| KzgSettings::load_trusted_setup_file(trusted_setup_file, 0))
+    });
+    #[cfg(feature = "memmap2")]
+    group.bench_function("mmap", |b| {
+        b.iter(|| KzgSettings::load_trusted_setup_mmap(trusted_setup_file, 0))
+    });
+    group.finish();
+
+    c.bench_function("blob_to_kzg_commitment", |b| {
+        b.iter(|| kzg_settings.blob_to_kzg_commitment(&blobs[0]))
+    });
+
+    c.bench_function("compute_kzg_proof", |b| {
+        b.iter(|| kzg_settings.compute_kzg_proof(&blobs[0], &z))
+    });
+
+    c.bench_function("compute_blob_kzg_proof", |b| {
+        b.iter(|| kzg_settings.compute_blob_kzg_proof(&blobs[0], &commitments[0]))
+    });
+
+    c.bench_function("verify_kzg_proof", |b| {
+        b.iter(|| {
+            kzg_settings.verify_kzg_proof(
+                &commitments[0],
+                &z,
+                &proof_with_eval.y,
+                &proof_with_eval.proof,
+            )
+        })
+    });
+
+    c.bench_function("verify_blob_kzg_proof", |b| {
+        b.iter(|| kzg_settings.verify_blob_kzg_proof(&blobs[0], &commitments[0], &proofs[0]))
+    });
+
+    let mut group = c.benchmark_group("verify_blob_kzg_proof_batch");
+    group.throughput(Throughput::Elements(MAX_BLOBS_PER_BLOCK as u64));
+    group.bench_function(format!("{MAX_BLOBS_PER_BLOCK} blobs"), |b| {
+        b.iter(|| kzg_settings.verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs))
+    });
+    group.finish();
+
+    c.bench_function("compute_cells_and_kzg_proofs", |b| {
+        b.iter(|| kzg_settings.compute_cells_and_kzg_proofs(&blobs[0]))
+    });
+
+    c.bench_function("compute_cells", |b| {
+        b.iter(|| kzg_settings.compute_cells(&blobs[0]))
+    });
+
+    c.bench_function("compute_cell_and_kzg_proof", |b| {
+        let cell_index = CellIndex::new(0).unwrap();
+        b.iter(|| kzg_settings.compute_cell_and_kzg_proof(&blobs[0], cell_index))
+    });
+
+    let (cells, cell_proofs) = kzg_settings
+        .compute_cells_and_kzg_proofs(&blobs[0])
+        .unwrap();
+    let cell_indices: Vec<CellIndex> = (0..CELLS_PER_EXT_BLOB as u64)
+        .map(|index| CellIndex::new(index).unwrap())
+        .collect();
+    let cell_commitments = vec![commitments[0]; CELLS_PER_EXT_BLOB];
+
+    let mut group = c.benchmark_group("verify_cell_kzg_proof_batch");
+    group.throughput(Throughput::Elements(CELLS_PER_EXT_BLOB as u64));
+    group.bench_function(format!("{CELLS_PER_EXT_BLOB} cells"), |b| {
+        b.iter(|| {
+            kzg_settings.verify_cell_kzg_proof_batch(
+                &cell_commitments,
+                &cell_indices,
+                &cells[..],
+                &cell_proofs[..],
+            )
+        })
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = criterion_benchmark
+}
+criterion_main!(benches);
@@ -122,6 +122,8 @@ fn make_bindings(
         .derive_hash(true)
         // Blobs are big, we don't want rust to liberally copy them around.
         .no_copy("Blob")
+        // We implement Debug for the byte types to print truncated hex instead of the raw bytes.
+        .no_debug("Bytes32|Bytes48|Blob|Cell")
         // Do not make fields public. If we want to modify them we can create setters/mutable
         // getters when necessary.
         .default_visibility(bindgen::FieldVisibilityKind::Private)
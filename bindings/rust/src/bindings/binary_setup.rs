@@ -0,0 +1,318 @@
+//! A compact binary encoding of a loaded trusted setup.
+//!
+//! The encoding is laid out as follows, with all integers in little-endian order:
+//!
+//! - the magic bytes `CKZG` and a one byte format version,
+//! - the precompute level as one byte,
+//! - the length of the points as a `u32`,
+//! - the compressed G1 points in monomial form, the compressed G1 points in Lagrange form and the
+//!   compressed G2 points in monomial form,
+//! - an FNV-1a `u64` checksum of everything before it.
+//!
+//! The checksum only guards against truncated or corrupted data. It is not a substitute for
+//! obtaining the encoded setup from a trusted source.
+//!
+//! Trusted setups distributed in monomial form only, as the compressed G1 points followed by the
+//! compressed G2 points, can be loaded with [`TrustedSetupFormat::Monomial`].
+
+use super::{
+    Error, KZGSettings, Precompute, BYTES_PER_G1_POINT, BYTES_PER_G2_POINT, NUM_G1_POINTS,
+    NUM_G2_POINTS,
+};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+const MAGIC: &[u8; 4] = b"CKZG";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = MAGIC.len() + 2 + 4;
+const CHECKSUM_LEN: usize = 8;
+const G1_BYTES_LEN: usize = NUM_G1_POINTS * BYTES_PER_G1_POINT;
+const G2_BYTES_LEN: usize = NUM_G2_POINTS * BYTES_PER_G2_POINT;
+const POINTS_LEN: usize = 2 * G1_BYTES_LEN + G2_BYTES_LEN;
+const MONOMIAL_LEN: usize = G1_BYTES_LEN + G2_BYTES_LEN;
+
+/// Returns the 64-bit FNV-1a hash of `bytes`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::InvalidTrustedSetup(format!("Invalid binary trusted setup: {reason}"))
+}
+
+/// The encodings of a trusted setup that can be loaded from bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrustedSetupFormat {
+    /// The text format of `trusted_setup.txt`, read by [`KZGSettings::from_bytes`].
+    Text,
+    /// The binary format written by [`KZGSettings::to_binary`].
+    Binary,
+    /// The compressed G1 points in monomial form followed by the compressed G2 points in monomial
+    /// form, read by [`KZGSettings::load_trusted_setup_monomial`].
+    Monomial,
+}
+
+impl TrustedSetupFormat {
+    /// Returns the format `bytes` appear to be in, judging by the binary format's magic bytes and
+    /// the length of the monomial format.
+    ///
+    /// This does not check that the bytes are valid in that format.
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(MAGIC) {
+            Self::Binary
+        } else if bytes.len() == MONOMIAL_LEN {
+            Self::Monomial
+        } else {
+            Self::Text
+        }
+    }
+}
+
+impl KZGSettings {
+    /// Encodes the trusted setup points and precompute level of these settings in a compact
+    /// binary format which can be loaded with [`from_binary`](Self::from_binary).
+    pub fn to_binary(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_LEN + POINTS_LEN + CHECKSUM_LEN);
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        out.push(self.precompute().as_u64() as u8);
+        out.extend_from_slice(&(POINTS_LEN as u32).to_le_bytes());
+
+        let mut g1_bytes = [0u8; BYTES_PER_G1_POINT];
+        let mut g2_bytes = [0u8; BYTES_PER_G2_POINT];
+        // SAFETY: the output buffers have the size of a compressed point.
+        unsafe {
+            for point in self.g1_points() {
+                blst::blst_p1_compress(g1_bytes.as_mut_ptr(), point);
+                out.extend_from_slice(&g1_bytes);
+            }
+            // The Lagrange points are stored in bit-reversed order, which is its own inverse.
+            let lagrange = self.g1_points_lagrange_brp();
+            let shift = u32::BITS - NUM_G1_POINTS.trailing_zeros();
+            for i in 0..NUM_G1_POINTS {
+                let point = &lagrange[((i as u32).reverse_bits() >> shift) as usize];
+                blst::blst_p1_compress(g1_bytes.as_mut_ptr(), point);
+                out.extend_from_slice(&g1_bytes);
+            }
+            for point in self.g2_points() {
+                blst::blst_p2_compress(g2_bytes.as_mut_ptr(), point);
+                out.extend_from_slice(&g2_bytes);
+            }
+        }
+
+        let checksum = fnv1a(&out);
+        out.extend_from_slice(&checksum.to_le_bytes());
+        out
+    }
+
+    /// Loads settings from the output of [`to_binary`](Self::to_binary), using the precompute
+    /// level they were encoded with.
+    ///
+    /// This skips parsing the hex of the text format. The points are still decompressed and
+    /// checked by the C library.
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_binary_with_precompute(bytes, None)
+    }
+
+    /// Same as [`from_binary`](Self::from_binary), using `precompute` instead of the encoded
+    /// precompute level if it is given.
+    fn from_binary_with_precompute(
+        bytes: &[u8],
+        precompute: Option<Precompute>,
+    ) -> Result<Self, Error> {
+        if bytes.len() != HEADER_LEN + POINTS_LEN + CHECKSUM_LEN {
+            return Err(invalid("unexpected length"));
+        }
+        let (data, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+        if fnv1a(data).to_le_bytes() != checksum {
+            return Err(invalid("checksum mismatch"));
+        }
+        let (header, points) = data.split_at(HEADER_LEN);
+        if &header[..MAGIC.len()] != MAGIC {
+            return Err(invalid("bad magic bytes"));
+        }
+        if header[4] != VERSION {
+            return Err(invalid("unsupported version"));
+        }
+        let encoded_precompute = Precompute::from_u64(u64::from(header[5]))?;
+        if header[6..] != (POINTS_LEN as u32).to_le_bytes() {
+            return Err(invalid("unexpected number of points"));
+        }
+
+        let (g1_monomial_bytes, points) = points.split_at(G1_BYTES_LEN);
+        let (g1_lagrange_bytes, g2_monomial_bytes) = points.split_at(G1_BYTES_LEN);
+        Self::load_trusted_setup(
+            g1_monomial_bytes,
+            g1_lagrange_bytes,
+            g2_monomial_bytes,
+            precompute.unwrap_or(encoded_precompute),
+        )
+    }
+
+    /// Loads settings from a trusted setup in the given format.
+    ///
+    /// Fails with [`Error::InvalidTrustedSetup`] if `bytes` are in another format. For the binary
+    /// format, `precompute` replaces the encoded precompute level.
+    pub fn from_bytes_in_format(
+        bytes: &[u8],
+        format: TrustedSetupFormat,
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
+        let detected = TrustedSetupFormat::detect(bytes);
+        if detected != format {
+            return Err(Error::InvalidTrustedSetup(format!(
+                "Expected a trusted setup in the {format:?} format, got the {detected:?} format"
+            )));
+        }
+        match format {
+            TrustedSetupFormat::Text => Self::from_bytes(bytes, precompute),
+            TrustedSetupFormat::Binary => {
+                Self::from_binary_with_precompute(bytes, Some(precompute.into()))
+            }
+            TrustedSetupFormat::Monomial => {
+                let (g1_monomial_bytes, g2_monomial_bytes) = bytes.split_at(G1_BYTES_LEN);
+                Self::load_trusted_setup_monomial(g1_monomial_bytes, g2_monomial_bytes, precompute)
+            }
+        }
+    }
+
+    /// Loads settings from a file written by
+    /// [`save_trusted_setup_bin`](Self::save_trusted_setup_bin).
+    #[cfg(feature = "std")]
+    pub fn load_trusted_setup_bin(file_path: &Path) -> Result<Self, Error> {
+        let bytes = std::fs::read(file_path).map_err(|e| {
+            Error::InvalidTrustedSetup(format!(
+                "Failed to read trusted setup file {file_path:?}: {e}"
+            ))
+        })?;
+        Self::from_binary(&bytes)
+    }
+
+    /// Writes the output of [`to_binary`](Self::to_binary) to a file, e.g. to load it with
+    /// [`load_trusted_setup_bin`](Self::load_trusted_setup_bin) on later runs.
+    #[cfg(feature = "std")]
+    pub fn save_trusted_setup_bin(&self, file_path: &Path) -> std::io::Result<()> {
+        std::fs::write(file_path, self.to_binary())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::generate_random_blob;
+    use super::super::*;
+    use super::{CHECKSUM_LEN, HEADER_LEN, POINTS_LEN};
+
+    #[test]
+    fn test_binary_roundtrip() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 2).unwrap();
+        let encoded = kzg_settings.to_binary();
+        assert_eq!(encoded.len(), HEADER_LEN + POINTS_LEN + CHECKSUM_LEN);
+
+        let decoded = KZGSettings::from_binary(&encoded).unwrap();
+        assert_eq!(decoded.precompute(), Precompute::level(2).unwrap());
+        assert_eq!(decoded.to_binary(), encoded);
+
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        assert_eq!(decoded.blob_to_kzg_commitment(&blob).unwrap(), commitment);
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+        assert_eq!(
+            decoded.compute_cells_and_kzg_proofs(&blob).unwrap(),
+            (cells, proofs)
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_in_format() {
+        let text = std::fs::read("src/trusted_setup.txt").unwrap();
+        let from_text =
+            KZGSettings::from_bytes_in_format(&text, TrustedSetupFormat::Text, 1).unwrap();
+        let binary = from_text.to_binary();
+        assert_eq!(TrustedSetupFormat::detect(&text), TrustedSetupFormat::Text);
+        assert_eq!(
+            TrustedSetupFormat::detect(&binary),
+            TrustedSetupFormat::Binary
+        );
+
+        let from_binary =
+            KZGSettings::from_bytes_in_format(&binary, TrustedSetupFormat::Binary, 1).unwrap();
+        assert_eq!(from_binary.to_binary(), binary);
+        let reprecomputed =
+            KZGSettings::from_bytes_in_format(&binary, TrustedSetupFormat::Binary, 0).unwrap();
+        assert_eq!(reprecomputed.precompute(), Precompute::NONE);
+
+        let monomial = [
+            &include_bytes!("../ethereum_kzg_settings/g1_monomial_bytes.bin")[..],
+            &include_bytes!("../ethereum_kzg_settings/g2_monomial_bytes.bin")[..],
+        ]
+        .concat();
+        assert_eq!(
+            TrustedSetupFormat::detect(&monomial),
+            TrustedSetupFormat::Monomial
+        );
+        let from_monomial =
+            KZGSettings::from_bytes_in_format(&monomial, TrustedSetupFormat::Monomial, 1).unwrap();
+        assert_eq!(from_monomial.to_binary(), binary);
+        #[cfg(feature = "sha2")]
+        assert_eq!(from_monomial.fingerprint(), from_text.fingerprint());
+
+        for (bytes, format) in [
+            (&text, TrustedSetupFormat::Binary),
+            (&text, TrustedSetupFormat::Monomial),
+            (&binary, TrustedSetupFormat::Text),
+            (&monomial, TrustedSetupFormat::Text),
+        ] {
+            assert!(matches!(
+                KZGSettings::from_bytes_in_format(bytes, format, 0),
+                Err(Error::InvalidTrustedSetup(_))
+            ));
+        }
+        assert!(matches!(
+            KZGSettings::load_trusted_setup_monomial(&monomial[1..], &[], 0),
+            Err(Error::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[test]
+    fn test_binary_file_roundtrip() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let bin_file =
+            std::env::temp_dir().join(format!("c-kzg-trusted-setup-{}.bin", std::process::id()));
+        kzg_settings.save_trusted_setup_bin(&bin_file).unwrap();
+        let loaded = KZGSettings::load_trusted_setup_bin(&bin_file);
+        std::fs::remove_file(&bin_file).unwrap();
+        assert_eq!(loaded.unwrap().to_binary(), kzg_settings.to_binary());
+
+        assert!(matches!(
+            KZGSettings::load_trusted_setup_bin(Path::new("does/not/exist")),
+            Err(Error::InvalidTrustedSetup(_))
+        ));
+        assert!(matches!(
+            KZGSettings::load_trusted_setup_bin(trusted_setup_file),
+            Err(Error::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[test]
+    fn test_binary_rejects_corruption() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let encoded = kzg_settings.to_binary();
+
+        assert!(KZGSettings::from_binary(&encoded[..encoded.len() - 1]).is_err());
+        for index in [0, 4, HEADER_LEN + 10, encoded.len() - 1] {
+            let mut corrupted = encoded.clone();
+            corrupted[index] ^= 1;
+            assert!(matches!(
+                KZGSettings::from_binary(&corrupted),
+                Err(Error::InvalidTrustedSetup(_))
+            ));
+        }
+    }
+}
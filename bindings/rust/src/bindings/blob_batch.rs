@@ -0,0 +1,114 @@
+//! Grouping of the blobs of a block with their commitments and proofs.
+
+use super::{Blob, Error, KZGCommitment, KZGProof, KZGSettings, VerificationResult};
+use alloc::vec::Vec;
+
+/// The maximum number of blobs in a block under EIP-4844.
+pub const MAX_BLOBS_PER_BLOCK: usize = 6;
+
+/// The blobs of a block together with their commitments and proofs.
+///
+/// The three are kept in parallel vectors, so that they can be passed to
+/// [`verify_blob_kzg_proof_batch`](KZGSettings::verify_blob_kzg_proof_batch) without copying,
+/// and [`push`](Self::push) is the only way to add to them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlobBatch {
+    blobs: Vec<Blob>,
+    commitments: Vec<KZGCommitment>,
+    proofs: Vec<KZGProof>,
+}
+
+impl BlobBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a blob with its commitment and proof, failing if the batch already holds
+    /// [`MAX_BLOBS_PER_BLOCK`] blobs.
+    pub fn push(
+        &mut self,
+        blob: Blob,
+        commitment: KZGCommitment,
+        proof: KZGProof,
+    ) -> Result<(), Error> {
+        if self.len() == MAX_BLOBS_PER_BLOCK {
+            return Err(Error::TooManyBlobs {
+                max: MAX_BLOBS_PER_BLOCK,
+            });
+        }
+        self.blobs.push(blob);
+        self.commitments.push(commitment);
+        self.proofs.push(proof);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.blobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blobs.is_empty()
+    }
+
+    pub fn blobs(&self) -> &[Blob] {
+        &self.blobs
+    }
+
+    pub fn commitments(&self) -> &[KZGCommitment] {
+        &self.commitments
+    }
+
+    pub fn proofs(&self) -> &[KZGProof] {
+        &self.proofs
+    }
+
+    /// Verifies all proofs of the batch at once. An empty batch is valid.
+    pub fn verify(&self, kzg_settings: &KZGSettings) -> Result<VerificationResult, Error> {
+        kzg_settings.verify_blob_kzg_proof_batch(&self.blobs, &self.commitments, &self.proofs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::generate_random_blob;
+    use super::super::*;
+    use super::MAX_BLOBS_PER_BLOCK;
+
+    #[test]
+    fn test_blob_batch() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut rng = rand::thread_rng();
+        let mut batch = BlobBatch::new();
+        assert!(batch.is_empty());
+        assert!(batch.verify(&kzg_settings).unwrap().is_valid());
+
+        for _ in 0..MAX_BLOBS_PER_BLOCK {
+            let blob = generate_random_blob(&mut rng);
+            let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+            let proof = kzg_settings
+                .compute_blob_kzg_proof(&blob, &commitment)
+                .unwrap();
+            batch.push(blob, commitment, proof).unwrap();
+        }
+        assert_eq!(batch.len(), MAX_BLOBS_PER_BLOCK);
+        assert_eq!(batch.commitments().len(), MAX_BLOBS_PER_BLOCK);
+        assert!(batch.verify(&kzg_settings).unwrap().is_valid());
+
+        let commitment = batch.commitments()[0];
+        let proof = batch.proofs()[0];
+        assert!(matches!(
+            batch.push(generate_random_blob(&mut rng), commitment, proof),
+            Err(Error::TooManyBlobs {
+                max: MAX_BLOBS_PER_BLOCK
+            })
+        ));
+
+        // A proof paired with the wrong blob fails the whole batch.
+        let mut swapped = BlobBatch::new();
+        swapped
+            .push(generate_random_blob(&mut rng), commitment, proof)
+            .unwrap();
+        assert!(!swapped.verify(&kzg_settings).unwrap().is_valid());
+    }
+}
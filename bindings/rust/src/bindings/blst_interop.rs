@@ -0,0 +1,101 @@
+//! Conversions between commitments and the uncompressed G1 points used by `blst`.
+
+use super::{Error, KZGCommitment, BYTES_PER_COMMITMENT};
+
+/// The length of an uncompressed G1 point.
+pub const BYTES_PER_G1_UNCOMPRESSED: usize = 2 * BYTES_PER_COMMITMENT;
+
+impl KZGCommitment {
+    /// Compresses an uncompressed G1 point, as serialized by [`blst::blst_p1_affine_serialize`],
+    /// into a commitment.
+    ///
+    /// Fails if the bytes are not a valid point in the G1 subgroup.
+    pub fn from_g1_uncompressed(bytes: &[u8; BYTES_PER_G1_UNCOMPRESSED]) -> Result<Self, Error> {
+        let mut point = blst::blst_p1_affine::default();
+        let mut commitment = [0; BYTES_PER_COMMITMENT];
+        // SAFETY: the input holds an uncompressed point and the output a compressed one.
+        unsafe {
+            let res = blst::blst_p1_deserialize(&mut point, bytes.as_ptr());
+            if res != blst::BLST_ERROR::BLST_SUCCESS {
+                return Err(Error::InvalidKzgCommitment(format!(
+                    "Invalid uncompressed G1 point: {res:?}"
+                )));
+            }
+            if !blst::blst_p1_affine_in_g1(&point) {
+                return Err(Error::InvalidKzgCommitment(
+                    "Uncompressed G1 point is not in the G1 subgroup".into(),
+                ));
+            }
+            blst::blst_p1_affine_compress(commitment.as_mut_ptr(), &point);
+        }
+        Ok(Self { bytes: commitment })
+    }
+
+    /// Decompresses the commitment into an uncompressed G1 point, as deserialized by
+    /// [`blst::blst_p1_deserialize`].
+    ///
+    /// Fails if the bytes of the commitment are not a valid compressed point.
+    pub fn to_g1_uncompressed(&self) -> Result<[u8; BYTES_PER_G1_UNCOMPRESSED], Error> {
+        let mut point = blst::blst_p1_affine::default();
+        let mut bytes = [0; BYTES_PER_G1_UNCOMPRESSED];
+        // SAFETY: the input holds a compressed point and the output an uncompressed one.
+        unsafe {
+            let res = blst::blst_p1_uncompress(&mut point, self.bytes.as_ptr());
+            if res != blst::BLST_ERROR::BLST_SUCCESS {
+                return Err(Error::InvalidKzgCommitment(format!(
+                    "Invalid compressed G1 point: {res:?}"
+                )));
+            }
+            blst::blst_p1_affine_serialize(bytes.as_mut_ptr(), &point);
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_g1_uncompressed_roundtrip() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let point = &kzg_settings.g1_points()[1];
+        let mut compressed = [0; BYTES_PER_COMMITMENT];
+        let mut expected = [0; BYTES_PER_G1_UNCOMPRESSED];
+        // SAFETY: the output buffers hold a compressed and an uncompressed point.
+        unsafe {
+            blst::blst_p1_compress(compressed.as_mut_ptr(), point);
+            blst::blst_p1_serialize(expected.as_mut_ptr(), point);
+        }
+        let commitment = KZGCommitment::from_bytes(&compressed).unwrap();
+        let uncompressed = commitment.to_g1_uncompressed().unwrap();
+        assert_eq!(uncompressed, expected);
+        assert_eq!(
+            KZGCommitment::from_g1_uncompressed(&uncompressed).unwrap(),
+            commitment
+        );
+
+        let infinity = KZGCommitment::from_bytes(&[&[0xc0][..], &[0; 47]].concat()).unwrap();
+        let uncompressed = infinity.to_g1_uncompressed().unwrap();
+        assert_eq!(
+            KZGCommitment::from_g1_uncompressed(&uncompressed).unwrap(),
+            infinity
+        );
+
+        let mut not_on_curve = uncompressed;
+        not_on_curve[0] = 0;
+        not_on_curve[95] = 1;
+        assert!(matches!(
+            KZGCommitment::from_g1_uncompressed(&not_on_curve),
+            Err(Error::InvalidKzgCommitment(_))
+        ));
+        assert!(matches!(
+            KZGCommitment::from_bytes(&[0; 48])
+                .unwrap()
+                .to_g1_uncompressed(),
+            Err(Error::InvalidKzgCommitment(_))
+        ));
+    }
+}
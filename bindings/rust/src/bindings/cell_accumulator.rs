@@ -0,0 +1,119 @@
+//! Incremental collection of cells for a single batch verification.
+
+use super::{Cell, CellIndex, Error, KZGCommitment, KZGProof, KZGSettings, VerificationResult};
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+/// Collects cells with their commitments and proofs as they arrive, to verify all of them with
+/// one call to [`verify_cell_kzg_proof_batch`](KZGSettings::verify_cell_kzg_proof_batch).
+///
+/// Each cell of a commitment can only be pushed once, so the accumulator holds at most
+/// `CELLS_PER_EXT_BLOB` cells per commitment.
+#[derive(Debug, Clone, Default)]
+pub struct CellProofAccumulator {
+    commitments: Vec<KZGCommitment>,
+    cell_indices: Vec<CellIndex>,
+    cells: Vec<Cell>,
+    proofs: Vec<KZGProof>,
+    seen: BTreeSet<(KZGCommitment, CellIndex)>,
+}
+
+impl CellProofAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a cell, failing with [`Error::DuplicateCell`] if a cell with the same commitment and
+    /// index was already pushed.
+    pub fn push(
+        &mut self,
+        commitment: KZGCommitment,
+        cell_index: CellIndex,
+        cell: Cell,
+        proof: KZGProof,
+    ) -> Result<(), Error> {
+        if !self.seen.insert((commitment, cell_index)) {
+            return Err(Error::DuplicateCell(cell_index.as_u64()));
+        }
+        self.commitments.push(commitment);
+        self.cell_indices.push(cell_index);
+        self.cells.push(cell);
+        self.proofs.push(proof);
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Verifies all pushed cells at once. An empty accumulator is valid.
+    pub fn verify(&self, kzg_settings: &KZGSettings) -> Result<VerificationResult, Error> {
+        kzg_settings.verify_cell_kzg_proof_batch(
+            &self.commitments,
+            &self.cell_indices,
+            &self.cells,
+            &self.proofs,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_cell_proof_accumulator() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut blob = [0u8; BYTES_PER_BLOB];
+        blob[BYTES_PER_FIELD_ELEMENT - 1] = 1;
+        let blob = Blob::new(blob);
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+
+        let mut accumulator = CellProofAccumulator::new();
+        assert!(accumulator.verify(&kzg_settings).unwrap().is_valid());
+        let indices = [0u64, 5, 127];
+        for &i in &indices {
+            let index = CellIndex::new(i).unwrap();
+            let i = i as usize;
+            accumulator
+                .push(commitment, index, cells[i], proofs[i])
+                .unwrap();
+        }
+        assert_eq!(accumulator.len(), indices.len());
+
+        let cell_indices: Vec<CellIndex> = indices
+            .iter()
+            .map(|&i| CellIndex::new(i).unwrap())
+            .collect();
+        let batch_cells: Vec<Cell> = indices.iter().map(|&i| cells[i as usize]).collect();
+        let batch_proofs: Vec<KZGProof> = indices.iter().map(|&i| proofs[i as usize]).collect();
+        let batch_result = kzg_settings
+            .verify_cell_kzg_proof_batch(
+                &[commitment; 3],
+                &cell_indices,
+                &batch_cells,
+                &batch_proofs,
+            )
+            .unwrap();
+        assert!(batch_result.is_valid());
+        assert_eq!(accumulator.verify(&kzg_settings).unwrap(), batch_result);
+
+        assert!(matches!(
+            accumulator.push(commitment, cell_indices[1], cells[5], proofs[5]),
+            Err(Error::DuplicateCell(5))
+        ));
+        assert_eq!(accumulator.len(), indices.len());
+
+        // A cell with the wrong proof makes the whole batch fail.
+        accumulator
+            .push(commitment, CellIndex::new(1).unwrap(), cells[1], proofs[2])
+            .unwrap();
+        assert!(!accumulator.verify(&kzg_settings).unwrap().is_valid());
+    }
+}
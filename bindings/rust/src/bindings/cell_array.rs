@@ -0,0 +1,113 @@
+//! A view of the cells of an extended blob.
+
+use super::{Cell, CellIndex, KZGProof, CELLS_PER_EXT_BLOB};
+use alloc::boxed::Box;
+
+/// The `CELLS_PER_EXT_BLOB` cells of an extended blob, as returned by
+/// [`compute_cells_and_kzg_proofs`](super::KZGSettings::compute_cells_and_kzg_proofs) and the
+/// recovery functions.
+///
+/// A blob itself cannot be viewed as cells, since they are evaluations of its polynomial over
+/// the extended domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellArray {
+    cells: Box<[Cell; CELLS_PER_EXT_BLOB]>,
+}
+
+impl CellArray {
+    /// Returns the cell at `index`, or `None` if it is not less than `CELLS_PER_EXT_BLOB`.
+    pub fn get_cell(&self, index: usize) -> Option<&Cell> {
+        self.cells.get(index)
+    }
+
+    /// Returns the cell at a cell index, which is always in range.
+    pub fn cell(&self, index: CellIndex) -> &Cell {
+        &self.cells[index.as_u64() as usize]
+    }
+
+    /// Returns an iterator over the cells and their indices.
+    pub fn iter_cells(&self) -> impl ExactSizeIterator<Item = (usize, &Cell)> {
+        self.cells.iter().enumerate()
+    }
+
+    pub fn into_inner(self) -> Box<[Cell; CELLS_PER_EXT_BLOB]> {
+        self.cells
+    }
+}
+
+impl From<Box<[Cell; CELLS_PER_EXT_BLOB]>> for CellArray {
+    fn from(cells: Box<[Cell; CELLS_PER_EXT_BLOB]>) -> Self {
+        Self { cells }
+    }
+}
+
+impl AsRef<[Cell]> for CellArray {
+    fn as_ref(&self) -> &[Cell] {
+        &self.cells[..]
+    }
+}
+
+/// Pairs the cells of an extended blob with their proofs and indices, in index order.
+///
+/// This is meant for the output of
+/// [`compute_cells_and_kzg_proofs`](super::KZGSettings::compute_cells_and_kzg_proofs) and
+/// [`recover_cells_and_kzg_proofs`](super::KZGSettings::recover_cells_and_kzg_proofs).
+pub fn enumerate_cells_and_proofs<'a>(
+    cells: &'a [Cell; CELLS_PER_EXT_BLOB],
+    proofs: &'a [KZGProof; CELLS_PER_EXT_BLOB],
+) -> impl ExactSizeIterator<Item = (CellIndex, &'a Cell, &'a KZGProof)> {
+    cells
+        .iter()
+        .zip(proofs)
+        .enumerate()
+        .map(|(i, (cell, proof))| (CellIndex(i as u64), cell, proof))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::generate_random_blob;
+    use super::super::*;
+
+    #[test]
+    fn test_cell_array() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let (cells, _) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+        let cell_array = CellArray::from(cells.clone());
+
+        assert_eq!(cell_array.iter_cells().len(), CELLS_PER_EXT_BLOB);
+        for (i, cell) in cell_array.iter_cells() {
+            assert_eq!(cell, &cells[i]);
+            assert_eq!(cell_array.get_cell(i), Some(cell));
+            assert_eq!(cell_array.cell(CellIndex::new(i as u64).unwrap()), cell);
+        }
+        assert_eq!(cell_array.get_cell(CELLS_PER_EXT_BLOB), None);
+        assert_eq!(cell_array.as_ref(), &cells[..]);
+        assert_eq!(cell_array.into_inner(), cells);
+    }
+
+    #[test]
+    fn test_enumerate_cells_and_proofs() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blob = Blob::new([0; BYTES_PER_BLOB]);
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+        let indices: Vec<CellIndex> = (0..CELLS_PER_EXT_BLOB as u64 / 2)
+            .map(|index| CellIndex::new(index).unwrap())
+            .collect();
+        let (recovered_cells, recovered_proofs) = kzg_settings
+            .recover_cells_and_kzg_proofs(&indices, &cells[..CELLS_PER_EXT_BLOB / 2])
+            .unwrap();
+
+        assert_eq!(recovered_proofs, proofs);
+
+        let items = enumerate_cells_and_proofs(&recovered_cells, &recovered_proofs);
+        assert_eq!(items.len(), CELLS_PER_EXT_BLOB);
+        for (i, (index, cell, proof)) in items.enumerate() {
+            assert_eq!(index.as_u64(), i as u64);
+            assert_eq!(cell, &recovered_cells[i]);
+            assert_eq!(proof, &recovered_proofs[i]);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Splitting of concatenated blobs without copying them.
+
+use super::{Blob, Error, BYTES_PER_BLOB};
+use alloc::vec::Vec;
+
+/// An iterator over the blobs in a byte slice, yielding references into the slice.
+///
+/// Iteration stops when fewer than `BYTES_PER_BLOB` bytes are left; those bytes are available
+/// from [`remainder`](Self::remainder).
+#[derive(Debug, Clone)]
+pub struct BlobChunker<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> BlobChunker<'a> {
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the bytes that have not been yielded as a blob yet.
+    pub const fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for BlobChunker<'a> {
+    type Item = &'a Blob;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.len() < BYTES_PER_BLOB {
+            return None;
+        }
+        let (blob, rest) = self.bytes.split_at(BYTES_PER_BLOB);
+        self.bytes = rest;
+        Blob::from_bytes_ref(blob).ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bytes.len() / BYTES_PER_BLOB;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for BlobChunker<'_> {}
+
+/// Splits `bytes` into blobs without copying them, failing if its length is not a multiple of
+/// `BYTES_PER_BLOB`.
+pub fn try_collect_blobs(bytes: &[u8]) -> Result<Vec<&Blob>, Error> {
+    let mut chunker = BlobChunker::new(bytes);
+    let blobs = chunker.by_ref().collect();
+    if !chunker.remainder().is_empty() {
+        return Err(Error::InvalidBytesLength(format!(
+            "Invalid byte length. Expected a multiple of {} got {}",
+            BYTES_PER_BLOB,
+            bytes.len(),
+        )));
+    }
+    Ok(blobs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blob_chunker() {
+        let mut bytes = vec![0u8; 3 * BYTES_PER_BLOB + 5];
+        for (i, chunk) in bytes.chunks_mut(BYTES_PER_BLOB).enumerate() {
+            chunk.fill(i as u8);
+        }
+
+        let mut chunker = BlobChunker::new(&bytes);
+        assert_eq!(chunker.len(), 3);
+        for i in 0..3 {
+            assert_eq!(chunker.next().unwrap().as_ref(), &[i; BYTES_PER_BLOB][..]);
+        }
+        assert!(chunker.next().is_none());
+        assert_eq!(chunker.remainder(), &[3; 5]);
+
+        assert!(BlobChunker::new(&bytes[..BYTES_PER_BLOB - 1])
+            .next()
+            .is_none());
+        assert!(matches!(
+            try_collect_blobs(&bytes),
+            Err(Error::InvalidBytesLength(_))
+        ));
+        let blobs = try_collect_blobs(&bytes[..2 * BYTES_PER_BLOB]).unwrap();
+        assert_eq!(blobs.len(), 2);
+        assert_eq!(blobs[1].as_ref(), &[1; BYTES_PER_BLOB][..]);
+        assert!(try_collect_blobs(&[]).unwrap().is_empty());
+    }
+}
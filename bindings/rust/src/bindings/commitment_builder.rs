@@ -0,0 +1,190 @@
+//! Incremental computation of the commitment to a blob.
+
+use super::{
+    is_valid_field_element, Blob, Bytes32, Error, KZGCommitment, KZGSettings, BYTES_PER_BLOB,
+    BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, FIELD_ELEMENTS_PER_BLOB,
+};
+use alloc::boxed::Box;
+
+/// Builds a blob field element by field element and computes the commitment to it.
+///
+/// [`set`](Self::set) only writes a field element, and [`build`](Self::build) then commits to
+/// the whole blob with a single multi-scalar multiplication. Once the commitment is known,
+/// [`update`](Self::update) adjusts it by `(new - old) * L_i`, where `L_i` is the G1 point of
+/// the trusted setup in Lagrange form for the field element at index `i`, instead of
+/// recomputing it.
+///
+/// The blob starts out zeroed, so its commitment is known and `update` can be used right away.
+#[derive(Debug, Clone)]
+pub struct CommitmentBuilder<'a> {
+    kzg_settings: &'a KZGSettings,
+    blob: Box<Blob>,
+    /// The commitment to `blob`, unless a field element was set since it was last computed.
+    commitment: Option<blst::blst_p1>,
+}
+
+impl<'a> CommitmentBuilder<'a> {
+    pub fn new(kzg_settings: &'a KZGSettings) -> Self {
+        Self {
+            kzg_settings,
+            blob: Box::new(Blob::new([0; BYTES_PER_BLOB])),
+            commitment: Some(blst::blst_p1::default()),
+        }
+    }
+
+    /// Returns the blob built so far.
+    pub fn blob(&self) -> &Blob {
+        &self.blob
+    }
+
+    /// Sets the field element at `index`. The commitment is recomputed on the next call to
+    /// [`build`](Self::build).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `FIELD_ELEMENTS_PER_BLOB`.
+    pub fn set(&mut self, index: usize, value: &Bytes32) -> Result<(), Error> {
+        self.write(index, value)?;
+        self.commitment = None;
+        Ok(())
+    }
+
+    /// Sets the field element at `index`, adjusting the commitment if it is known.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `FIELD_ELEMENTS_PER_BLOB`.
+    pub fn update(&mut self, index: usize, value: &Bytes32) -> Result<(), Error> {
+        let old = self.write(index, value)?;
+        if let Some(commitment) = &mut self.commitment {
+            let commitment: *mut blst::blst_p1 = commitment;
+            let old = fr_from_bytes(&old);
+            let new = fr_from_bytes(value);
+            let mut delta = blst::blst_fr::default();
+            let mut scalar = blst::blst_scalar::default();
+            let mut term = blst::blst_p1::default();
+            let point = &self.kzg_settings.g1_points_lagrange_brp()[index];
+            // SAFETY: the scalar buffer holds a whole scalar, of which the low 255 bits are used.
+            unsafe {
+                blst::blst_fr_sub(&mut delta, &new, &old);
+                blst::blst_scalar_from_fr(&mut scalar, &delta);
+                blst::blst_p1_mult(&mut term, point, scalar.b.as_ptr(), 255);
+                blst::blst_p1_add_or_double(commitment, commitment, &term);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the commitment to the blob built so far, computing it if a field element was
+    /// [`set`](Self::set) since the last call.
+    pub fn build(&mut self) -> Result<KZGCommitment, Error> {
+        if let Some(commitment) = &self.commitment {
+            let mut bytes = [0; BYTES_PER_COMMITMENT];
+            // SAFETY: the output buffer holds a compressed point.
+            unsafe { blst::blst_p1_compress(bytes.as_mut_ptr(), commitment) };
+            return Ok(KZGCommitment { bytes });
+        }
+
+        let commitment = self.kzg_settings.blob_to_kzg_commitment(&self.blob)?;
+        let mut affine = blst::blst_p1_affine::default();
+        let mut point = blst::blst_p1::default();
+        // SAFETY: the input holds a compressed point.
+        unsafe {
+            let res = blst::blst_p1_uncompress(&mut affine, commitment.bytes.as_ptr());
+            debug_assert_eq!(res, blst::BLST_ERROR::BLST_SUCCESS);
+            blst::blst_p1_from_affine(&mut point, &affine);
+        }
+        self.commitment = Some(point);
+        Ok(commitment)
+    }
+
+    /// Writes the field element at `index`, returning the previous one.
+    fn write(&mut self, index: usize, value: &Bytes32) -> Result<Bytes32, Error> {
+        assert!(index < FIELD_ELEMENTS_PER_BLOB, "index out of range");
+        if !is_valid_field_element(value) {
+            return Err(Error::NonCanonicalFieldElement(index));
+        }
+        let start = index * BYTES_PER_FIELD_ELEMENT;
+        let element = &mut self.blob.bytes[start..start + BYTES_PER_FIELD_ELEMENT];
+        let old = Bytes32::from_bytes(element)?;
+        element.copy_from_slice(&value.bytes);
+        Ok(old)
+    }
+}
+
+/// Converts a canonical big-endian field element to the representation used by `blst`.
+fn fr_from_bytes(bytes: &Bytes32) -> blst::blst_fr {
+    let mut scalar = blst::blst_scalar::default();
+    let mut fr = blst::blst_fr::default();
+    // SAFETY: the input holds a whole scalar.
+    unsafe {
+        blst::blst_scalar_from_bendian(&mut scalar, bytes.bytes.as_ptr());
+        blst::blst_fr_from_scalar(&mut fr, &scalar);
+    }
+    fr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+    use rand::Rng;
+
+    fn random_field_element(rng: &mut impl Rng) -> Bytes32 {
+        let mut bytes = [0u8; 32];
+        rng.fill(&mut bytes[1..]);
+        Bytes32::new(bytes)
+    }
+
+    #[test]
+    fn test_commitment_builder() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut rng = rand::thread_rng();
+
+        let mut builder = CommitmentBuilder::new(&kzg_settings);
+        assert_eq!(
+            builder.build().unwrap(),
+            kzg_settings.blob_to_kzg_commitment(builder.blob()).unwrap()
+        );
+        for _ in 0..8 {
+            let index = rng.gen_range(0..FIELD_ELEMENTS_PER_BLOB);
+            builder
+                .update(index, &random_field_element(&mut rng))
+                .unwrap();
+        }
+        assert_eq!(
+            builder.build().unwrap(),
+            kzg_settings.blob_to_kzg_commitment(builder.blob()).unwrap()
+        );
+
+        for index in 0..FIELD_ELEMENTS_PER_BLOB {
+            builder.set(index, &random_field_element(&mut rng)).unwrap();
+        }
+        let expected = kzg_settings.blob_to_kzg_commitment(builder.blob()).unwrap();
+        assert_eq!(builder.build().unwrap(), expected);
+
+        // Setting a field element to its current value keeps the commitment.
+        let first = *builder.blob().field_elements().next().unwrap();
+        builder.update(0, &first).unwrap();
+        assert_eq!(builder.build().unwrap(), expected);
+
+        for _ in 0..8 {
+            let index = rng.gen_range(0..FIELD_ELEMENTS_PER_BLOB);
+            builder
+                .update(index, &random_field_element(&mut rng))
+                .unwrap();
+            assert_eq!(
+                builder.build().unwrap(),
+                kzg_settings.blob_to_kzg_commitment(builder.blob()).unwrap()
+            );
+        }
+
+        let blob = builder.blob().clone();
+        assert!(matches!(
+            builder.update(3, &bls_modulus()),
+            Err(Error::NonCanonicalFieldElement(3))
+        ));
+        assert_eq!(builder.blob(), &blob);
+    }
+}
@@ -0,0 +1,86 @@
+//! A common interface to the fixed-size byte wrappers.
+
+use super::{
+    Blob, Bytes32, Bytes48, Cell, KZGCommitment, KZGProof, BYTES_PER_BLOB, BYTES_PER_CELL,
+    BYTES_PER_COMMITMENT, BYTES_PER_PROOF,
+};
+
+/// A wrapper around exactly `N` bytes, for code that is generic over the byte types.
+///
+/// The conversions never fail, and unlike the inherent `from_bytes` methods of the types,
+/// [`from_bytes`](Self::from_bytes) takes an array rather than a slice of any length.
+pub trait FixedBytes<const N: usize>: Sized {
+    /// The number of bytes of the type.
+    const SIZE: usize = N;
+
+    fn as_bytes(&self) -> &[u8; N];
+
+    fn into_bytes(self) -> [u8; N];
+
+    fn from_bytes(bytes: [u8; N]) -> Self;
+}
+
+macro_rules! impl_fixed_bytes {
+    ($($type:ty => $size:expr),* $(,)?) => {
+        $(
+            impl FixedBytes<$size> for $type {
+                fn as_bytes(&self) -> &[u8; $size] {
+                    &self.bytes
+                }
+
+                fn into_bytes(self) -> [u8; $size] {
+                    self.bytes
+                }
+
+                fn from_bytes(bytes: [u8; $size]) -> Self {
+                    Self { bytes }
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_bytes! {
+    Blob => BYTES_PER_BLOB,
+    Bytes32 => 32,
+    Bytes48 => 48,
+    Cell => BYTES_PER_CELL,
+    KZGCommitment => BYTES_PER_COMMITMENT,
+    KZGProof => BYTES_PER_PROOF,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::FixedBytes;
+    use alloc::string::String;
+    use rand::Rng;
+
+    fn hex_roundtrip<T: FixedBytes<N>, const N: usize>(value: T) -> T {
+        assert_eq!(T::SIZE, N);
+        let encoded: String = hex::encode(value.as_bytes());
+        let mut bytes = [0u8; N];
+        hex::decode_to_slice(encoded, &mut bytes).unwrap();
+        T::from_bytes(bytes)
+    }
+
+    #[test]
+    fn test_fixed_bytes() {
+        let mut rng = rand::thread_rng();
+        let bytes32 = Bytes32::new(rng.gen());
+        assert_eq!(hex_roundtrip(bytes32), bytes32);
+        let mut bytes48 = [0u8; 48];
+        rng.fill(&mut bytes48[..]);
+        let commitment = KZGCommitment::from(bytes48);
+        assert_eq!(hex_roundtrip(commitment), commitment);
+        rng.fill(&mut bytes48[..]);
+        let proof = KZGProof::from(bytes48);
+        assert_eq!(FixedBytes::into_bytes(proof), proof.bytes);
+
+        let mut cell = [0u8; BYTES_PER_CELL];
+        rng.fill(&mut cell[..]);
+        let cell = <Cell as FixedBytes<BYTES_PER_CELL>>::from_bytes(cell);
+        assert_eq!(hex_roundtrip(cell), cell);
+        assert_eq!(<Cell as FixedBytes<BYTES_PER_CELL>>::SIZE, BYTES_PER_CELL);
+    }
+}
@@ -65,19 +65,19 @@ pub enum C_KZG_RET {
 }
 #[doc = " An array of 32 bytes. Represents an untrusted (potentially invalid) field element."]
 #[repr(C)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
 pub struct Bytes32 {
     bytes: [u8; 32usize],
 }
 #[doc = " An array of 48 bytes. Represents an untrusted (potentially invalid) commitment/proof."]
 #[repr(C)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
 pub struct Bytes48 {
     bytes: [u8; 48usize],
 }
 #[doc = " A basic blob data."]
 #[repr(C)]
-#[derive(Debug, Hash, PartialEq, Eq)]
+#[derive(Hash, PartialEq, Eq)]
 pub struct Blob {
     bytes: [u8; 131072usize],
 }
@@ -108,7 +108,7 @@ pub struct KZGSettings {
 }
 #[doc = " A single cell for a blob."]
 #[repr(C)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
 pub struct Cell {
     bytes: [u8; 2048usize],
 }
@@ -125,6 +125,12 @@ extern "C" {
         z_bytes: *const Bytes32,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn evaluate_blob_at(
+        y_out: *mut Bytes32,
+        blob: *const Blob,
+        z_bytes: *const Bytes32,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn compute_blob_kzg_proof(
         out: *mut KZGProof,
         blob: *const Blob,
@@ -146,6 +152,13 @@ extern "C" {
         proof_bytes: *const Bytes48,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn verify_blob_kzg_proof_unchecked(
+        ok: *mut bool,
+        blob: *const Blob,
+        commitment_bytes: *const Bytes48,
+        proof_bytes: *const Bytes48,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn verify_blob_kzg_proof_batch(
         ok: *mut bool,
         blobs: *const Blob,
@@ -154,12 +167,28 @@ extern "C" {
         n: u64,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn verify_kzg_proof_batch(
+        ok: *mut bool,
+        commitments_bytes: *const Bytes48,
+        zs_bytes: *const Bytes32,
+        ys_bytes: *const Bytes32,
+        proofs_bytes: *const Bytes48,
+        n: u64,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn compute_cells_and_kzg_proofs(
         cells: *mut Cell,
         proofs: *mut KZGProof,
         blob: *const Blob,
         s: *const KZGSettings,
     ) -> C_KZG_RET;
+    pub fn compute_cell_and_kzg_proof(
+        cell: *mut Cell,
+        proof: *mut KZGProof,
+        blob: *const Blob,
+        cell_index: u64,
+        s: *const KZGSettings,
+    ) -> C_KZG_RET;
     pub fn recover_cells_and_kzg_proofs(
         recovered_cells: *mut Cell,
         recovered_proofs: *mut KZGProof,
@@ -187,6 +216,14 @@ extern "C" {
         num_g2_monomial_bytes: u64,
         precompute: u64,
     ) -> C_KZG_RET;
+    pub fn load_trusted_setup_monomial(
+        out: *mut KZGSettings,
+        g1_monomial_bytes: *const u8,
+        num_g1_monomial_bytes: u64,
+        g2_monomial_bytes: *const u8,
+        num_g2_monomial_bytes: u64,
+        precompute: u64,
+    ) -> C_KZG_RET;
     pub fn load_trusted_setup_file(
         out: *mut KZGSettings,
         in_: *mut FILE,
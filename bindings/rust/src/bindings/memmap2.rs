@@ -0,0 +1,49 @@
+//! Loading of the trusted setup from a memory-mapped file, backed by memmap2.
+
+use super::{Error, KZGSettings, Precompute};
+use memmap2::Mmap;
+use std::{fs::File, path::Path};
+
+impl KZGSettings {
+    /// Same as [`load_trusted_setup_file`](Self::load_trusted_setup_file), but maps the file
+    /// into memory instead of reading it, so that it is paged in as it is parsed.
+    ///
+    /// The mapping is released before returning, as the settings keep their own copy of the
+    /// points. The file must not be modified while it is being loaded.
+    pub fn load_trusted_setup_mmap(
+        file_path: &Path,
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
+        let map_error = |e| {
+            Error::InvalidTrustedSetup(format!(
+                "Failed to map trusted setup file {file_path:?}: {e}"
+            ))
+        };
+        let file = File::open(file_path).map_err(map_error)?;
+        // SAFETY: the mapping is only read while parsing, and the caller guarantees that the
+        // file is not modified in the meantime.
+        let mmap = unsafe { Mmap::map(&file) }.map_err(map_error)?;
+        Self::from_bytes(&mmap, precompute)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_load_trusted_setup_mmap() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let file_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mmap_settings = KZGSettings::load_trusted_setup_mmap(trusted_setup_file, 0).unwrap();
+        let blob = Blob::new([0; BYTES_PER_BLOB]);
+        assert_eq!(
+            mmap_settings.blob_to_kzg_commitment(&blob).unwrap(),
+            file_settings.blob_to_kzg_commitment(&blob).unwrap()
+        );
+
+        let error = KZGSettings::load_trusted_setup_mmap(Path::new("does/not/exist"), 0);
+        assert!(matches!(error, Err(Error::InvalidTrustedSetup(_))));
+    }
+}
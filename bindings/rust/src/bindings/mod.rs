@@ -3,10 +3,67 @@
 #![allow(non_snake_case)]
 #![allow(dead_code)]
 
+mod binary_setup;
+mod blob_batch;
+#[cfg(feature = "blst-interop")]
+mod blst_interop;
+mod cell_accumulator;
+mod cell_array;
+mod chunker;
+mod commitment_builder;
+mod fixed_bytes;
+#[cfg(feature = "memmap2")]
+mod memmap2;
+mod packing;
+#[cfg(feature = "rayon")]
+mod rayon;
 #[cfg(feature = "serde")]
 mod serde;
+mod settings_builder;
+#[cfg(feature = "std")]
+mod settings_pool;
+#[cfg(feature = "sha2")]
+mod sha2;
 #[cfg(test)]
 mod test_formats;
+#[cfg(feature = "test-utils")]
+mod test_utils;
+#[cfg(feature = "tokio")]
+mod tokio;
+mod verification;
+#[cfg(feature = "wasm")]
+mod wasm_api;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+pub use self::binary_setup::TrustedSetupFormat;
+pub use self::blob_batch::{BlobBatch, MAX_BLOBS_PER_BLOCK};
+#[cfg(feature = "blst-interop")]
+pub use self::blst_interop::BYTES_PER_G1_UNCOMPRESSED;
+pub use self::cell_accumulator::CellProofAccumulator;
+pub use self::cell_array::{enumerate_cells_and_proofs, CellArray};
+pub use self::chunker::{try_collect_blobs, BlobChunker};
+pub use self::commitment_builder::CommitmentBuilder;
+pub use self::fixed_bytes::FixedBytes;
+pub use self::packing::{pack_to_blob, unpack_from_blob, MAX_PACKED_BYTES};
+#[cfg(feature = "rayon")]
+pub use self::rayon::PARALLEL_BATCH_THRESHOLD;
+pub use self::settings_builder::KzgSettingsBuilder;
+#[cfg(feature = "std")]
+pub use self::settings_pool::{KzgSettingsPool, PoolGuard};
+#[cfg(feature = "sha2")]
+pub use self::sha2::MAINNET_TRUSTED_SETUP_SHA256;
+#[cfg(feature = "test-utils")]
+pub use self::test_utils::{sample_blob, sample_commitment_and_proof};
+#[cfg(feature = "tokio")]
+pub use self::tokio::{
+    blob_to_kzg_commitment_async, compute_blob_kzg_proof_async, compute_cells_and_kzg_proofs_async,
+    compute_kzg_proof_async, recover_cells_and_kzg_proofs_async, verify_blob_kzg_proof_async,
+    verify_blob_kzg_proof_batch_async, verify_cell_kzg_proof_batch_async, verify_kzg_proof_async,
+};
+pub use self::verification::VerificationResult;
+#[cfg(feature = "wasm")]
+pub use self::wasm_api::WasmKzgSettings;
 
 use arbitrary::Arbitrary;
 
@@ -14,11 +71,13 @@ include!("./generated.rs");
 
 use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::ffi::CStr;
 use core::fmt;
 use core::mem::MaybeUninit;
 use core::ops::{Deref, DerefMut};
+use core::str::FromStr;
 
 #[cfg(feature = "std")]
 use alloc::ffi::CString;
@@ -35,30 +94,68 @@ const NUM_G1_POINTS: usize = 4096;
 /// 65 is fixed and is used for providing multiproofs up to 64 field elements.
 const NUM_G2_POINTS: usize = 65;
 
-/// A trusted (valid) KZG commitment.
+/// A KZG commitment.
+///
+/// The functions that take commitments check that they are valid G1 points.
 // NOTE: this is a type alias to the struct Bytes48, same as [`KZGProof`] in the C header files. To
 //       facilitate type safety: proofs and commitments should not be interchangeable, we use a
-//       custom implementation.
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
+//       custom implementation. Untyped bytes can be converted with `From<Bytes48>`.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(not(feature = "subtle"), derive(PartialEq))]
+#[cfg_attr(feature = "subtle", allow(clippy::derived_hash_with_manual_eq))]
 pub struct KZGCommitment {
     bytes: [u8; BYTES_PER_COMMITMENT],
 }
 
-/// A trusted (valid) KZG proof.
+/// A KZG proof.
+///
+/// The functions that take proofs check that they are valid G1 points.
 // NOTE: this is a type alias to the struct Bytes48, same as [`KZGCommitment`] in the C header
 //       files. To facilitate type safety: proofs and commitments should not be interchangeable, we
-//       use a custom implementation.
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
+//       use a custom implementation. Untyped bytes can be converted with `From<Bytes48>`.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(not(feature = "subtle"), derive(PartialEq))]
+#[cfg_attr(feature = "subtle", allow(clippy::derived_hash_with_manual_eq))]
 pub struct KZGProof {
     bytes: [u8; BYTES_PER_PROOF],
 }
 
+/// A KZG proof together with the evaluation `y = p(z)` that it proves.
+///
+/// ```
+/// # use c_kzg::{Blob, Bytes32, KzgSettings, BYTES_PER_BLOB};
+/// # use std::path::Path;
+/// let settings = KzgSettings::load_trusted_setup_file(Path::new("src/trusted_setup.txt"), 0)?;
+/// let blob = Blob::new([0; BYTES_PER_BLOB]);
+/// let commitment = settings.blob_to_kzg_commitment(&blob)?;
+/// let z = Bytes32::new([1; 32]);
+///
+/// let proof = settings.compute_kzg_proof(&blob, &z)?;
+/// assert!(settings.verify_kzg_proof(&commitment, &z, &proof.y, &proof.proof)?.is_valid());
+/// # Ok::<(), c_kzg::Error>(())
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct KzgProofWithEval {
+    /// The proof.
+    pub proof: KZGProof,
+    /// The evaluation of the blob polynomial at `z`.
+    pub y: Bytes32,
+}
+
+impl From<KzgProofWithEval> for (KZGProof, Bytes32) {
+    fn from(value: KzgProofWithEval) -> Self {
+        (value.proof, value.y)
+    }
+}
+
 #[derive(Debug)]
 pub enum Error {
     /// Wrong number of bytes.
     InvalidBytesLength(String),
+    /// The blob does not have `BYTES_PER_BLOB` bytes.
+    InvalidBlobLength { expected: usize, got: usize },
     /// The hex string is invalid.
     InvalidHexFormat(String),
     /// The KZG proof is invalid.
@@ -71,12 +168,41 @@ pub enum Error {
     MismatchLength(String),
     /// Loading the trusted setup failed.
     LoadingTrustedSetupFailed(KzgErrors),
-    /// The underlying c-kzg library returned an error.
-    CError(C_KZG_RET),
+    /// An input was rejected by the c-kzg library, e.g. a field element that is not canonical or
+    /// a commitment or proof that is not a valid G1 point.
+    InvalidArgument,
+    /// The c-kzg library could not allocate memory.
+    AllocationFailed,
+    /// The c-kzg library failed for any other reason.
+    InternalError(C_KZG_RET),
+    /// The cell index is not less than `CELLS_PER_EXT_BLOB`.
+    InvalidCellIndex(u64),
+    /// Fewer than half of the cells of an extended blob were provided for recovery.
+    InsufficientCells { required: usize, got: usize },
+    /// The SHA-256 hash of the trusted setup file does not match the expected hash.
+    TrustedSetupChecksum { expected: [u8; 32], got: [u8; 32] },
+    /// The element at `index` of a batch failed with `error`.
+    InvalidBatchElement { index: usize, error: Box<Error> },
+    /// The field element at this index of a blob is not less than the BLS12-381 scalar modulus.
+    NonCanonicalFieldElement(usize),
+    /// A batch of blobs is already at its maximum size.
+    TooManyBlobs { max: usize },
+    /// The global settings were already initialized.
+    GlobalSettingsAlreadyInitialized,
+    /// A cell with this index was already added for the same commitment.
+    DuplicateCell(u64),
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InternalError(e) => Some(e),
+            Self::InvalidBatchElement { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -87,8 +213,91 @@ impl fmt::Display for Error {
             | Self::InvalidKzgCommitment(s)
             | Self::InvalidTrustedSetup(s)
             | Self::MismatchLength(s) => f.write_str(s),
+            Self::InvalidBlobLength { expected, got } => {
+                write!(f, "Invalid blob length. Expected {expected} got {got}")
+            }
             Self::LoadingTrustedSetupFailed(s) => write!(f, "KzgErrors: {:?}", s),
-            Self::CError(s) => fmt::Debug::fmt(s, f),
+            Self::InvalidArgument => f.write_str("Invalid argument"),
+            Self::AllocationFailed => f.write_str("Memory allocation failed"),
+            Self::InternalError(s) => write!(f, "Internal c-kzg error: {s}"),
+            Self::InvalidCellIndex(index) => write!(
+                f,
+                "Invalid cell index {index}, must be less than {CELLS_PER_EXT_BLOB}"
+            ),
+            Self::InsufficientCells { required, got } => write!(
+                f,
+                "Not enough cells to recover. Expected at least {required} got {got}"
+            ),
+            Self::TrustedSetupChecksum { expected, got } => write!(
+                f,
+                "Trusted setup checksum mismatch. Expected 0x{} got 0x{}",
+                hex::encode(expected),
+                hex::encode(got)
+            ),
+            Self::InvalidBatchElement { index, error } => {
+                write!(f, "Invalid batch element at index {index}: {error}")
+            }
+            Self::NonCanonicalFieldElement(index) => {
+                write!(f, "Non-canonical field element at index {index}")
+            }
+            Self::TooManyBlobs { max } => {
+                write!(f, "Too many blobs. Expected at most {max}")
+            }
+            Self::GlobalSettingsAlreadyInitialized => {
+                f.write_str("Global settings are already initialized")
+            }
+            Self::DuplicateCell(index) => {
+                write!(f, "Duplicate cell {index} for the same commitment")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for C_KZG_RET {}
+
+impl fmt::Display for C_KZG_RET {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::C_KZG_OK => "success",
+            Self::C_KZG_BADARGS => "invalid argument",
+            Self::C_KZG_ERROR => "internal error",
+            Self::C_KZG_MALLOC => "could not allocate memory",
+        })
+    }
+}
+
+/// Maps the return codes of the c-kzg library to errors:
+///
+/// - `C_KZG_BADARGS` becomes [`Error::InvalidArgument`]. The C functions return it for any input
+///   they reject: a field element which is not canonical, bytes which are not a valid G1 point
+///   in the subgroup, a cell index out of range, repeated cell indices or too few cells for
+///   recovery, or a malformed trusted setup.
+/// - `C_KZG_MALLOC` becomes [`Error::AllocationFailed`].
+/// - `C_KZG_ERROR` becomes [`Error::InternalError`]. It indicates a bug in the library and should
+///   never be returned.
+///
+/// `C_KZG_OK` is not an error, and the bindings never convert it. It would also become
+/// [`Error::InternalError`].
+impl From<C_KZG_RET> for Error {
+    fn from(e: C_KZG_RET) -> Self {
+        match e {
+            C_KZG_RET::C_KZG_BADARGS => Error::InvalidArgument,
+            C_KZG_RET::C_KZG_MALLOC => Error::AllocationFailed,
+            e => Error::InternalError(e),
+        }
+    }
+}
+
+impl Error {
+    /// Returns the code of the c-kzg library this error was converted from, or `None` if it was
+    /// detected by the bindings.
+    pub fn c_kzg_ret(&self) -> Option<C_KZG_RET> {
+        match self {
+            Self::InvalidArgument => Some(C_KZG_RET::C_KZG_BADARGS),
+            Self::AllocationFailed => Some(C_KZG_RET::C_KZG_MALLOC),
+            Self::InternalError(e) => Some(*e),
+            _ => None,
         }
     }
 }
@@ -112,8 +321,10 @@ pub enum KzgErrors {
     /// File is not properly formatted.
     FileFormatError,
     /// Not able to parse to usize.
+    #[deprecated(note = "malformed trusted setups are reported as `Error::InvalidTrustedSetup`")]
     ParseError,
     /// Number of points does not match what is expected.
+    #[deprecated(note = "malformed trusted setups are reported as `Error::InvalidTrustedSetup`")]
     MismatchedNumberOfPoints,
 }
 
@@ -124,6 +335,258 @@ pub fn hex_to_bytes(hex_str: &str) -> Result<Vec<u8>, Error> {
         .map_err(|e| Error::InvalidHexFormat(format!("Failed to decode hex: {}", e)))
 }
 
+/// The window size used to precompute the fixed-base MSM tables when loading a trusted setup.
+///
+/// The tables are only used by the cell proof computation. A larger window makes computing
+/// proofs faster but the memory for the tables grows exponentially: a window of `w` bits takes
+/// `0.75 MiB * 2^(w - 1)`, i.e. 6 MiB with 4 bits, 96 MiB with 8 bits and 12 GiB with the
+/// maximum of 15 bits. There are diminishing returns after 8 bits.
+///
+/// Every level built with [`Precompute::level`] or [`Precompute::from_u64`] is valid. Raw `u64`
+/// values converted with `From` are checked by the trusted setup loading functions instead, so
+/// that existing `u64` callers keep compiling.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Precompute(PrecomputeValue);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum PrecomputeValue {
+    /// A window size of at most `Precompute::MAX` bits, where 0 means no tables.
+    Bits(u8),
+    /// A raw value larger than `Precompute::MAX`, kept so that the loading functions can report
+    /// it when they reject it.
+    OutOfRange(u64),
+}
+
+impl Default for PrecomputeValue {
+    fn default() -> Self {
+        Self::Bits(0)
+    }
+}
+
+impl Precompute {
+    /// Do not precompute any tables.
+    pub const NONE: Self = Self(PrecomputeValue::Bits(0));
+
+    /// The largest window size supported by the C library.
+    pub const MAX: u8 = 15;
+
+    /// Returns the precompute level with a window of `bits` bits, or an error if it is larger
+    /// than [`Precompute::MAX`]. A window of 0 bits is [`Precompute::NONE`].
+    pub fn level(bits: u8) -> Result<Self, Error> {
+        Self::from_u64(u64::from(bits))
+    }
+
+    /// Returns the precompute level for `value`, or an error if it is larger than
+    /// [`Precompute::MAX`].
+    pub fn from_u64(value: u64) -> Result<Self, Error> {
+        if value <= u64::from(Self::MAX) {
+            Ok(Self(PrecomputeValue::Bits(value as u8)))
+        } else {
+            Err(Error::InvalidTrustedSetup(format!(
+                "Invalid precompute value {value}, must be at most {}",
+                Self::MAX
+            )))
+        }
+    }
+
+    /// Returns the precompute level as the raw value passed to the C library.
+    pub const fn as_u64(self) -> u64 {
+        match self.0 {
+            PrecomputeValue::Bits(bits) => bits as u64,
+            PrecomputeValue::OutOfRange(value) => value,
+        }
+    }
+
+    /// Returns the raw value, or an error if the level is out of range.
+    fn checked_u64(self) -> Result<u64, Error> {
+        Self::from_u64(self.as_u64()).map(Self::as_u64)
+    }
+}
+
+/// Out-of-range values are not rejected here but by the trusted setup loading functions.
+impl From<u64> for Precompute {
+    fn from(value: u64) -> Self {
+        Self::from_u64(value).unwrap_or(Self(PrecomputeValue::OutOfRange(value)))
+    }
+}
+
+/// The index of a cell in an extended blob, which is always less than `CELLS_PER_EXT_BLOB`.
+///
+/// Existing `&[u64]` indices can be checked and reused without copying with
+/// [`CellIndex::from_slice`].
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CellIndex(u64);
+
+impl CellIndex {
+    /// Returns the cell index `index`, or an error if it is not less than `CELLS_PER_EXT_BLOB`.
+    pub const fn new(index: u64) -> Result<Self, Error> {
+        if index < CELLS_PER_EXT_BLOB as u64 {
+            Ok(Self(index))
+        } else {
+            Err(Error::InvalidCellIndex(index))
+        }
+    }
+
+    /// Checks that all `indices` are valid cell indices and reinterprets them as such.
+    pub fn from_slice(indices: &[u64]) -> Result<&[Self], Error> {
+        if let Some(&index) = indices.iter().find(|&&i| i >= CELLS_PER_EXT_BLOB as u64) {
+            return Err(Error::InvalidCellIndex(index));
+        }
+        // SAFETY: `CellIndex` is a `repr(transparent)` wrapper around `u64` and all indices are
+        // in range.
+        Ok(unsafe { core::slice::from_raw_parts(indices.as_ptr().cast(), indices.len()) })
+    }
+
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl TryFrom<u64> for CellIndex {
+    type Error = Error;
+
+    fn try_from(index: u64) -> Result<Self, Self::Error> {
+        Self::new(index)
+    }
+}
+
+impl From<CellIndex> for u64 {
+    fn from(index: CellIndex) -> Self {
+        index.0
+    }
+}
+
+impl fmt::Display for CellIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Allocates zeroed cells for an extended blob directly on the heap.
+fn new_boxed_cells() -> Box<[Cell; CELLS_PER_EXT_BLOB]> {
+    vec![Cell::default(); CELLS_PER_EXT_BLOB]
+        .into_boxed_slice()
+        .try_into()
+        .expect("vector has CELLS_PER_EXT_BLOB cells")
+}
+
+/// The BLS12-381 scalar field modulus as big-endian 64-bit limbs, most significant first.
+const BLS_MODULUS_LIMBS: [u64; 4] = [
+    0x73eda753299d7d48,
+    0x3339d80809a1d805,
+    0x53bda402fffe5bfe,
+    0xffffffff00000001,
+];
+
+/// Returns the BLS12-381 scalar field modulus in big-endian order.
+pub const fn bls_modulus() -> Bytes32 {
+    let mut bytes = [0u8; 32];
+    let mut i = 0;
+    while i < BLS_MODULUS_LIMBS.len() {
+        let limb = BLS_MODULUS_LIMBS[i].to_be_bytes();
+        let mut j = 0;
+        while j < limb.len() {
+            bytes[i * 8 + j] = limb[j];
+            j += 1;
+        }
+        i += 1;
+    }
+    Bytes32::new(bytes)
+}
+
+/// Converts a field element of the C library to big-endian bytes.
+fn fr_to_bytes(fr: &fr_t) -> Bytes32 {
+    let mut scalar = blst::blst_scalar::default();
+    let mut bytes = [0u8; 32];
+    // SAFETY: the generated field element type has the same layout as the one in `blst`, and the
+    // output buffer holds a whole scalar.
+    unsafe {
+        blst::blst_scalar_from_fr(&mut scalar, (fr as *const fr_t).cast());
+        blst::blst_bendian_from_scalar(bytes.as_mut_ptr(), &scalar);
+    }
+    Bytes32::new(bytes)
+}
+
+/// Returns whether `bytes` is a big-endian scalar less than the BLS12-381 scalar field modulus.
+///
+/// The comparison runs in constant time.
+pub fn is_valid_field_element(bytes: &Bytes32) -> bool {
+    // Subtract the modulus limb by limb, starting from the least significant one. The value is
+    // in range exactly when the subtraction borrows out of the most significant limb.
+    let mut borrow = false;
+    for (chunk, modulus) in bytes.bytes.chunks_exact(8).zip(BLS_MODULUS_LIMBS).rev() {
+        let limb = u64::from_be_bytes(chunk.try_into().expect("chunk has 8 bytes"));
+        let (difference, borrow_limb) = limb.overflowing_sub(modulus);
+        let (_, borrow_carry) = difference.overflowing_sub(u64::from(borrow));
+        borrow = borrow_limb | borrow_carry;
+    }
+    borrow
+}
+
+/// Checks that every field element of `blob` is less than the BLS12-381 scalar field modulus.
+///
+/// On failure, returns the index and value of the first out-of-range element.
+pub fn validate_blob_field_elements(blob: &Blob) -> Result<(), (usize, Bytes32)> {
+    match blob
+        .field_elements()
+        .enumerate()
+        .find(|(_, element)| !is_valid_field_element(element))
+    {
+        Some((index, element)) => Err((index, *element)),
+        None => Ok(()),
+    }
+}
+
+/// Checks the inputs shared by the cell recovery functions before calling into C.
+fn check_recovery_inputs(cell_indices: &[CellIndex], cells: &[Cell]) -> Result<(), Error> {
+    if cell_indices.len() != cells.len() {
+        return Err(Error::MismatchLength(format!(
+            "There are {} cell indices and {} cells",
+            cell_indices.len(),
+            cells.len()
+        )));
+    }
+    if cells.len() < CELLS_PER_EXT_BLOB / 2 {
+        return Err(Error::InsufficientCells {
+            required: CELLS_PER_EXT_BLOB / 2,
+            got: cells.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Returns the error for an invalid line of a trusted setup file.
+fn invalid_setup_line(number: usize, reason: String) -> Error {
+    Error::InvalidTrustedSetup(format!("Invalid trusted setup: line {number}: {reason}"))
+}
+
+/// Returns the next line of a trusted setup file and its number, failing if the file ended
+/// before the `expected` value.
+fn next_setup_line<'a>(
+    lines: &mut impl Iterator<Item = (&'a str, usize)>,
+    expected: &str,
+) -> Result<(&'a str, usize), Error> {
+    lines.next().ok_or_else(|| {
+        Error::InvalidTrustedSetup(format!(
+            "Invalid trusted setup: unexpected end of file, expected {expected}"
+        ))
+    })
+}
+
+/// Parses the next line of a trusted setup file as a point count.
+fn parse_setup_count<'a>(
+    lines: &mut impl Iterator<Item = (&'a str, usize)>,
+    expected: &str,
+) -> Result<usize, Error> {
+    let (line, number) = next_setup_line(lines, expected)?;
+    line.parse()
+        .map_err(|e| invalid_setup_line(number, format!("invalid {expected}: {e}")))
+}
+
+/// A [`KZGSettings`] that can be cheaply cloned and shared across threads.
+pub type SharedKzgSettings = Arc<KZGSettings>;
+
 /// Holds the parameters of a kzg trusted setup ceremony.
 impl KZGSettings {
     /// Initializes a trusted setup from a flat array of `FIELD_ELEMENTS_PER_BLOB` G1 points in monomial form, a flat
@@ -133,8 +596,9 @@ impl KZGSettings {
         g1_monomial_bytes: &[u8],
         g1_lagrange_bytes: &[u8],
         g2_monomial_bytes: &[u8],
-        precompute: u64,
+        precompute: impl Into<Precompute>,
     ) -> Result<Self, Error> {
+        let precompute = precompute.into().checked_u64()?;
         let mut kzg_settings = MaybeUninit::<KZGSettings>::uninit();
         unsafe {
             let res = load_trusted_setup(
@@ -157,6 +621,112 @@ impl KZGSettings {
         }
     }
 
+    /// Initializes a trusted setup from a flat array of `FIELD_ELEMENTS_PER_BLOB` G1 points in
+    /// monomial form and a flat array of 65 G2 points in monomial form.
+    ///
+    /// The G1 points in Lagrange form are derived from the monomial ones with an inverse FFT, so
+    /// the settings are the same as those loaded with
+    /// [`load_trusted_setup`](Self::load_trusted_setup) from the same setup.
+    pub fn load_trusted_setup_monomial(
+        g1_monomial_bytes: &[u8],
+        g2_monomial_bytes: &[u8],
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
+        let precompute = precompute.into().checked_u64()?;
+        let mut kzg_settings = MaybeUninit::<KZGSettings>::uninit();
+        unsafe {
+            let res = load_trusted_setup_monomial(
+                kzg_settings.as_mut_ptr(),
+                g1_monomial_bytes.as_ptr().cast(),
+                g1_monomial_bytes.len() as u64,
+                g2_monomial_bytes.as_ptr().cast(),
+                g2_monomial_bytes.len() as u64,
+                precompute,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(kzg_settings.assume_init())
+            } else {
+                Err(Error::InvalidTrustedSetup(format!(
+                    "Invalid trusted setup: {res:?}",
+                )))
+            }
+        }
+    }
+
+    /// Wraps the settings in an `Arc` so they can be shared across threads.
+    pub fn into_shared(self) -> SharedKzgSettings {
+        Arc::new(self)
+    }
+
+    /// Returns the `FIELD_ELEMENTS_PER_BLOB` G1 points of the trusted setup in monomial form.
+    ///
+    /// The points are borrowed from the settings in the projective form used by `blst`. They can
+    /// be compressed to bytes with [`blst::blst_p1_compress`].
+    pub fn g1_points(&self) -> &[blst::blst_p1] {
+        // SAFETY: the array was allocated with `NUM_G1_POINTS` elements when the settings were
+        // loaded and lives as long as `self`. The generated point type has the same layout as the
+        // one in the `blst` crate.
+        unsafe { core::slice::from_raw_parts(self.g1_values_monomial.cast(), NUM_G1_POINTS) }
+    }
+
+    /// Returns the `FIELD_ELEMENTS_PER_BLOB` G1 points of the trusted setup in Lagrange form, in
+    /// bit-reversed order.
+    pub fn g1_points_lagrange_brp(&self) -> &[blst::blst_p1] {
+        // SAFETY: see `g1_points`.
+        unsafe { core::slice::from_raw_parts(self.g1_values_lagrange_brp.cast(), NUM_G1_POINTS) }
+    }
+
+    /// Returns the `FIELD_ELEMENTS_PER_EXT_BLOB + 1` powers of the primitive
+    /// `FIELD_ELEMENTS_PER_EXT_BLOB`-th root of unity used by the settings, in order. The first
+    /// and last elements are one.
+    pub fn roots_of_unity(&self) -> impl ExactSizeIterator<Item = Bytes32> + '_ {
+        // SAFETY: the array was allocated with `FIELD_ELEMENTS_PER_EXT_BLOB + 1` elements when the
+        // settings were loaded and lives as long as `self`.
+        let roots = unsafe {
+            core::slice::from_raw_parts(self.roots_of_unity, FIELD_ELEMENTS_PER_EXT_BLOB + 1)
+        };
+        roots.iter().map(fr_to_bytes)
+    }
+
+    /// Returns the `FIELD_ELEMENTS_PER_EXT_BLOB` roots of unity in bit-reversed order.
+    ///
+    /// The first `FIELD_ELEMENTS_PER_BLOB` of them are the evaluation points of the blob
+    /// polynomial: the field element at index `i` of a blob is its evaluation at the root at
+    /// index `i`.
+    pub fn brp_roots_of_unity(&self) -> impl ExactSizeIterator<Item = Bytes32> + '_ {
+        // SAFETY: the array was allocated with `FIELD_ELEMENTS_PER_EXT_BLOB` elements when the
+        // settings were loaded and lives as long as `self`.
+        let roots = unsafe {
+            core::slice::from_raw_parts(self.brp_roots_of_unity, FIELD_ELEMENTS_PER_EXT_BLOB)
+        };
+        roots.iter().map(fr_to_bytes)
+    }
+
+    /// Returns the `NUM_G2_POINTS` G2 points of the trusted setup in monomial form.
+    pub fn g2_points(&self) -> &[blst::blst_p2] {
+        // SAFETY: see `g1_points`.
+        unsafe { core::slice::from_raw_parts(self.g2_values_monomial.cast(), NUM_G2_POINTS) }
+    }
+
+    /// Returns the number of G1 points of the trusted setup, in each of the monomial and Lagrange
+    /// forms.
+    ///
+    /// This is always `FIELD_ELEMENTS_PER_BLOB`, as setups of any other size are rejected when
+    /// loading.
+    pub fn num_g1_points(&self) -> usize {
+        self.g1_points().len()
+    }
+
+    /// Returns the number of G2 points of the trusted setup, which is always 65.
+    pub fn num_g2_points(&self) -> usize {
+        self.g2_points().len()
+    }
+
+    /// Returns the precompute level these settings were loaded with.
+    pub fn precompute(&self) -> Precompute {
+        Precompute::from(self.wbits as u64)
+    }
+
     /// Loads the trusted setup parameters from a file. The file format is as follows:
     ///
     /// FIELD_ELEMENTS_PER_BLOB
@@ -165,7 +735,10 @@ impl KZGSettings {
     /// 65 g2 byte values in monomial form
     /// FIELD_ELEMENT_PER_BLOB g1 byte values in monomial form
     #[cfg(feature = "std")]
-    pub fn load_trusted_setup_file(file_path: &Path, precompute: u64) -> Result<Self, Error> {
+    pub fn load_trusted_setup_file(
+        file_path: &Path,
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
         #[cfg(unix)]
         let file_path_bytes = {
             use std::os::unix::prelude::OsStrExt;
@@ -185,71 +758,109 @@ impl KZGSettings {
         Self::load_trusted_setup_file_inner(&file_path, precompute)
     }
 
+    /// Loads the trusted setup parameters from a reader, in the same format as
+    /// [`load_trusted_setup_file`](Self::load_trusted_setup_file).
+    #[cfg(feature = "std")]
+    pub fn load_trusted_setup_from_reader<R: std::io::Read>(
+        mut reader: R,
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
+        let mut trusted_setup = String::new();
+        reader
+            .read_to_string(&mut trusted_setup)
+            .map_err(|_| KzgErrors::IOError)?;
+        Self::parse_kzg_trusted_setup(&trusted_setup, precompute)
+    }
+
+    /// Parses the raw contents of a KZG trusted setup file, e.g. one embedded with
+    /// `include_bytes!`, without touching the filesystem.
+    ///
+    /// The contents must be in the text format described in
+    /// [`load_trusted_setup_file`](Self::load_trusted_setup_file).
+    pub fn from_bytes(
+        setup_bytes: &[u8],
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
+        let trusted_setup =
+            core::str::from_utf8(setup_bytes).map_err(|_| KzgErrors::FileFormatError)?;
+        Self::parse_kzg_trusted_setup(trusted_setup, precompute)
+    }
+
     /// Parses the contents of a KZG trusted setup file into a KzgSettings.
-    pub fn parse_kzg_trusted_setup(trusted_setup: &str, precompute: u64) -> Result<Self, Error> {
-        let mut lines = trusted_setup.lines();
-
-        // Load number of g1 points
-        let n_g1 = lines
-            .next()
-            .ok_or(KzgErrors::FileFormatError)?
-            .parse::<usize>()
-            .map_err(|_| KzgErrors::ParseError)?;
+    ///
+    /// Lines may end with either LF or CRLF.
+    pub fn parse_kzg_trusted_setup(
+        trusted_setup: &str,
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
+        let mut lines = trusted_setup.lines().zip(1..);
+
+        let n_g1 = parse_setup_count(&mut lines, "number of g1 points")?;
         if n_g1 != NUM_G1_POINTS {
-            return Err(KzgErrors::MismatchedNumberOfPoints.into());
+            return Err(invalid_setup_line(
+                1,
+                format!("expected {NUM_G1_POINTS} g1 points, got {n_g1}"),
+            ));
         }
-
-        // Load number of g2 points
-        let n_g2 = lines
-            .next()
-            .ok_or(KzgErrors::FileFormatError)?
-            .parse::<usize>()
-            .map_err(|_| KzgErrors::ParseError)?;
+        let n_g2 = parse_setup_count(&mut lines, "number of g2 points")?;
         if n_g2 != NUM_G2_POINTS {
-            return Err(KzgErrors::MismatchedNumberOfPoints.into());
+            return Err(invalid_setup_line(
+                2,
+                format!("expected {NUM_G2_POINTS} g2 points, got {n_g2}"),
+            ));
         }
 
         let mut g1_lagrange_bytes = alloc::boxed::Box::new([0; BYTES_PER_G1_POINT * NUM_G1_POINTS]);
         let mut g2_monomial_bytes = alloc::boxed::Box::new([0; BYTES_PER_G2_POINT * NUM_G2_POINTS]);
         let mut g1_monomial_bytes = alloc::boxed::Box::new([0; BYTES_PER_G1_POINT * NUM_G1_POINTS]);
 
-        // Load g1 Lagrange bytes
-        g1_lagrange_bytes
-            .chunks_mut(BYTES_PER_G1_POINT)
-            .map(|chunk| {
-                let line = lines.next().ok_or(KzgErrors::FileFormatError)?;
-                hex::decode_to_slice(line, chunk).map_err(|_| KzgErrors::ParseError)
-            })
-            .collect::<Result<(), KzgErrors>>()?;
-
-        // Load g2 monomial bytes
-        g2_monomial_bytes
-            .chunks_mut(BYTES_PER_G2_POINT)
-            .map(|chunk| {
-                let line = lines.next().ok_or(KzgErrors::FileFormatError)?;
-                hex::decode_to_slice(line, chunk).map_err(|_| KzgErrors::ParseError)
-            })
-            .collect::<Result<(), KzgErrors>>()?;
-
-        // Load g1 monomial bytes
-        g1_monomial_bytes
-            .chunks_mut(BYTES_PER_G1_POINT)
-            .map(|chunk| {
-                let line = lines.next().ok_or(KzgErrors::FileFormatError)?;
-                hex::decode_to_slice(line, chunk).map_err(|_| KzgErrors::ParseError)
-            })
-            .collect::<Result<(), KzgErrors>>()?;
+        for (bytes, point_len, name) in [
+            (
+                &mut g1_lagrange_bytes[..],
+                BYTES_PER_G1_POINT,
+                "g1 point in Lagrange form",
+            ),
+            (
+                &mut g2_monomial_bytes[..],
+                BYTES_PER_G2_POINT,
+                "g2 point in monomial form",
+            ),
+            (
+                &mut g1_monomial_bytes[..],
+                BYTES_PER_G1_POINT,
+                "g1 point in monomial form",
+            ),
+        ] {
+            for chunk in bytes.chunks_mut(point_len) {
+                let (line, number) = next_setup_line(&mut lines, name)?;
+                hex::decode_to_slice(line, chunk)
+                    .map_err(|e| invalid_setup_line(number, format!("invalid {name}: {e}")))?;
+            }
+        }
 
-        if lines.next().is_some() {
-            return Err(KzgErrors::FileFormatError.into());
+        if let Some((_, number)) = lines.next() {
+            return Err(invalid_setup_line(
+                number,
+                "unexpected line after the last point".into(),
+            ));
         }
 
-        Self::load_trusted_setup(
+        let result = Self::load_trusted_setup(
             g1_monomial_bytes.as_ref(),
             g1_lagrange_bytes.as_ref(),
             g2_monomial_bytes.as_ref(),
             precompute,
-        )
+        );
+
+        #[cfg(feature = "zeroize")]
+        {
+            use ::zeroize::Zeroize;
+            g1_lagrange_bytes.zeroize();
+            g2_monomial_bytes.zeroize();
+            g1_monomial_bytes.zeroize();
+        }
+
+        result
     }
 
     /// Loads the trusted setup parameters from a file. The file format is as follows:
@@ -260,7 +871,10 @@ impl KZGSettings {
     /// 65 g2 byte values in monomial form
     /// FIELD_ELEMENT_PER_BLOB g1 byte values in monomial form
     #[cfg(not(feature = "std"))]
-    pub fn load_trusted_setup_file(file_path: &CStr, precompute: u64) -> Result<Self, Error> {
+    pub fn load_trusted_setup_file(
+        file_path: &CStr,
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
         Self::load_trusted_setup_file_inner(file_path, precompute)
     }
 
@@ -271,10 +885,11 @@ impl KZGSettings {
     /// .
     pub fn load_trusted_setup_file_inner(
         file_path: &CStr,
-        precompute: u64,
+        precompute: impl Into<Precompute>,
     ) -> Result<Self, Error> {
-        // SAFETY: `b"r\0"` is a valid null-terminated string.
-        const MODE: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked(b"r\0") };
+        const MODE: &CStr = c"r";
+
+        let precompute = precompute.into().checked_u64()?;
 
         // SAFETY:
         // - .as_ptr(): pointer is not dangling because file_path has not been dropped.
@@ -309,6 +924,19 @@ impl KZGSettings {
         result
     }
 
+    /// Checks that every field element of `blob` is canonical, without computing a commitment.
+    ///
+    /// Field elements are big-endian, as in the rest of the library. On failure the error holds
+    /// the index of the first non-canonical element.
+    pub fn validate_blob(&self, blob: &Blob) -> Result<(), Error> {
+        validate_blob_field_elements(blob)
+            .map_err(|(index, _)| Error::NonCanonicalFieldElement(index))
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, err)
+    )]
     pub fn blob_to_kzg_commitment(&self, blob: &Blob) -> Result<KZGCommitment, Error> {
         let mut kzg_commitment: MaybeUninit<KZGCommitment> = MaybeUninit::uninit();
         unsafe {
@@ -316,16 +944,51 @@ impl KZGSettings {
             if let C_KZG_RET::C_KZG_OK = res {
                 Ok(kzg_commitment.assume_init())
             } else {
-                Err(Error::CError(res))
+                Err(res.into())
             }
         }
     }
 
+    /// Same as [`blob_to_kzg_commitment`](Self::blob_to_kzg_commitment), but reads the blob
+    /// directly from `bytes` without copying it into a [`Blob`].
+    pub fn blob_to_kzg_commitment_from_bytes(&self, bytes: &[u8]) -> Result<KZGCommitment, Error> {
+        self.blob_to_kzg_commitment(Blob::from_bytes_ref(bytes)?)
+    }
+
+    /// Computes the commitments of all blobs, in the same order as the blobs.
+    ///
+    /// Fails with [`Error::InvalidBatchElement`] for the first blob that cannot be committed to,
+    /// without committing to the blobs after it. With the `rayon` feature the blobs are committed
+    /// to in parallel; the result, including the reported error, is the same as without it.
+    pub fn blobs_to_kzg_commitments(&self, blobs: &[Blob]) -> Result<Vec<KZGCommitment>, Error> {
+        #[cfg(feature = "rayon")]
+        return self::rayon::try_par_map(blobs, |blob| self.blob_to_kzg_commitment(blob));
+
+        #[cfg(not(feature = "rayon"))]
+        blobs
+            .iter()
+            .enumerate()
+            .map(|(index, blob)| {
+                self.blob_to_kzg_commitment(blob)
+                    .map_err(|error| Error::InvalidBatchElement {
+                        index,
+                        error: Box::new(error),
+                    })
+            })
+            .collect()
+    }
+
+    /// Computes the proof for the evaluation of the blob polynomial at `z`, along with the
+    /// evaluation `y` itself.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, err)
+    )]
     pub fn compute_kzg_proof(
         &self,
         blob: &Blob,
         z_bytes: &Bytes32,
-    ) -> Result<(KZGProof, Bytes32), Error> {
+    ) -> Result<KzgProofWithEval, Error> {
         let mut kzg_proof = MaybeUninit::<KZGProof>::uninit();
         let mut y_out = MaybeUninit::<Bytes32>::uninit();
         unsafe {
@@ -337,95 +1000,225 @@ impl KZGSettings {
                 self,
             );
             if let C_KZG_RET::C_KZG_OK = res {
-                Ok((kzg_proof.assume_init(), y_out.assume_init()))
+                Ok(KzgProofWithEval {
+                    proof: kzg_proof.assume_init(),
+                    y: y_out.assume_init(),
+                })
+            } else {
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Evaluates the polynomial of `blob` at `z`, which must be a canonical field element.
+    ///
+    /// This returns the same `y` as [`compute_kzg_proof`](Self::compute_kzg_proof) without
+    /// computing the proof.
+    pub fn evaluate_blob_at(&self, blob: &Blob, z: &Bytes32) -> Result<Bytes32, Error> {
+        let mut y_out = MaybeUninit::<Bytes32>::uninit();
+        unsafe {
+            let res = evaluate_blob_at(y_out.as_mut_ptr(), blob, z, self);
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(y_out.assume_init())
             } else {
-                Err(Error::CError(res))
+                Err(res.into())
             }
         }
     }
 
+    /// Same as [`compute_kzg_proof`](Self::compute_kzg_proof), but reads the blob directly from
+    /// `blob_bytes` without copying it into a [`Blob`].
+    pub fn compute_kzg_proof_from_bytes(
+        &self,
+        blob_bytes: &[u8],
+        z_bytes: &[u8],
+    ) -> Result<KzgProofWithEval, Error> {
+        self.compute_kzg_proof(
+            Blob::from_bytes_ref(blob_bytes)?,
+            &Bytes32::from_bytes(z_bytes)?,
+        )
+    }
+
     pub fn compute_blob_kzg_proof(
         &self,
         blob: &Blob,
-        commitment_bytes: &Bytes48,
+        commitment: &KZGCommitment,
     ) -> Result<KZGProof, Error> {
         let mut kzg_proof = MaybeUninit::<KZGProof>::uninit();
         unsafe {
-            let res = compute_blob_kzg_proof(kzg_proof.as_mut_ptr(), blob, commitment_bytes, self);
+            let res =
+                compute_blob_kzg_proof(kzg_proof.as_mut_ptr(), blob, commitment.as_bytes48(), self);
             if let C_KZG_RET::C_KZG_OK = res {
                 Ok(kzg_proof.assume_init())
             } else {
-                Err(Error::CError(res))
+                Err(res.into())
             }
         }
     }
 
+    /// Verifies that `proof` opens `commitment` to `y` at `z`.
+    ///
+    /// Returns a [`VerificationResult`] that is not valid for a well-formed proof that does not
+    /// verify, and an error if any input is malformed, e.g. a commitment or proof that is not a
+    /// valid G1 point or a non-canonical field element. The other verification functions report
+    /// the two cases the same way.
     pub fn verify_kzg_proof(
         &self,
-        commitment_bytes: &Bytes48,
+        commitment: &KZGCommitment,
         z_bytes: &Bytes32,
         y_bytes: &Bytes32,
-        proof_bytes: &Bytes48,
-    ) -> Result<bool, Error> {
+        proof: &KZGProof,
+    ) -> Result<VerificationResult, Error> {
         let mut verified: MaybeUninit<bool> = MaybeUninit::uninit();
         unsafe {
             let res = verify_kzg_proof(
                 verified.as_mut_ptr(),
-                commitment_bytes,
+                commitment.as_bytes48(),
                 z_bytes,
                 y_bytes,
-                proof_bytes,
+                proof.as_bytes48(),
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(verified.assume_init().into())
+            } else {
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Verifies many proofs of [`verify_kzg_proof`](Self::verify_kzg_proof) at once. The result
+    /// is only valid if all of them verify.
+    ///
+    /// The pairing checks are combined with a random linear combination, which is much faster
+    /// than verifying each proof on its own.
+    pub fn verify_kzg_proof_batch(
+        &self,
+        commitments: &[KZGCommitment],
+        zs: &[Bytes32],
+        ys: &[Bytes32],
+        proofs: &[KZGProof],
+    ) -> Result<VerificationResult, Error> {
+        if commitments.len() != zs.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} commitments and {} zs",
+                commitments.len(),
+                zs.len()
+            )));
+        }
+        if commitments.len() != ys.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} commitments and {} ys",
+                commitments.len(),
+                ys.len()
+            )));
+        }
+        if commitments.len() != proofs.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} commitments and {} proofs",
+                commitments.len(),
+                proofs.len()
+            )));
+        }
+        let mut verified: MaybeUninit<bool> = MaybeUninit::uninit();
+        unsafe {
+            let res = verify_kzg_proof_batch(
+                verified.as_mut_ptr(),
+                commitments.as_ptr().cast(),
+                zs.as_ptr(),
+                ys.as_ptr(),
+                proofs.as_ptr().cast(),
+                commitments.len() as u64,
                 self,
             );
             if let C_KZG_RET::C_KZG_OK = res {
-                Ok(verified.assume_init())
+                Ok(verified.assume_init().into())
             } else {
-                Err(Error::CError(res))
+                Err(res.into())
             }
         }
     }
 
+    /// Verifies that `proof` is the proof of `blob` for `commitment`, as computed by
+    /// [`compute_blob_kzg_proof`](Self::compute_blob_kzg_proof).
     pub fn verify_blob_kzg_proof(
         &self,
         blob: &Blob,
-        commitment_bytes: &Bytes48,
-        proof_bytes: &Bytes48,
-    ) -> Result<bool, Error> {
+        commitment: &KZGCommitment,
+        proof: &KZGProof,
+    ) -> Result<VerificationResult, Error> {
         let mut verified: MaybeUninit<bool> = MaybeUninit::uninit();
         unsafe {
             let res = verify_blob_kzg_proof(
                 verified.as_mut_ptr(),
                 blob,
-                commitment_bytes,
-                proof_bytes,
+                commitment.as_bytes48(),
+                proof.as_bytes48(),
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(verified.assume_init().into())
+            } else {
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Same as [`verify_blob_kzg_proof`](Self::verify_blob_kzg_proof), but skips checking that
+    /// the commitment and proof are in the G1 subgroup.
+    ///
+    /// # Security
+    ///
+    /// This must only be used for commitments and proofs from a trusted source, e.g. ones that
+    /// were computed locally or already verified. The points are still checked to be on the
+    /// curve, but the result for points outside of the subgroup is meaningless, and such points
+    /// can make an invalid proof verify.
+    pub fn verify_blob_kzg_proof_unchecked(
+        &self,
+        blob: &Blob,
+        commitment: &KZGCommitment,
+        proof: &KZGProof,
+    ) -> Result<VerificationResult, Error> {
+        let mut verified: MaybeUninit<bool> = MaybeUninit::uninit();
+        unsafe {
+            let res = verify_blob_kzg_proof_unchecked(
+                verified.as_mut_ptr(),
+                blob,
+                commitment.as_bytes48(),
+                proof.as_bytes48(),
                 self,
             );
             if let C_KZG_RET::C_KZG_OK = res {
-                Ok(verified.assume_init())
+                Ok(verified.assume_init().into())
             } else {
-                Err(Error::CError(res))
+                Err(res.into())
             }
         }
     }
 
+    /// Verifies many blob proofs at once. The result is only valid if all of them verify.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(blobs = blobs.len()), err)
+    )]
     pub fn verify_blob_kzg_proof_batch(
         &self,
         blobs: &[Blob],
-        commitments_bytes: &[Bytes48],
-        proofs_bytes: &[Bytes48],
-    ) -> Result<bool, Error> {
-        if blobs.len() != commitments_bytes.len() {
+        commitments: &[KZGCommitment],
+        proofs: &[KZGProof],
+    ) -> Result<VerificationResult, Error> {
+        if blobs.len() != commitments.len() {
             return Err(Error::MismatchLength(format!(
                 "There are {} blobs and {} commitments",
                 blobs.len(),
-                commitments_bytes.len()
+                commitments.len()
             )));
         }
-        if blobs.len() != proofs_bytes.len() {
+        if blobs.len() != proofs.len() {
             return Err(Error::MismatchLength(format!(
                 "There are {} blobs and {} proofs",
                 blobs.len(),
-                proofs_bytes.len()
+                proofs.len()
             )));
         }
         let mut verified: MaybeUninit<bool> = MaybeUninit::uninit();
@@ -433,19 +1226,59 @@ impl KZGSettings {
             let res = verify_blob_kzg_proof_batch(
                 verified.as_mut_ptr(),
                 blobs.as_ptr(),
-                commitments_bytes.as_ptr(),
-                proofs_bytes.as_ptr(),
+                commitments.as_ptr().cast(),
+                proofs.as_ptr().cast(),
                 blobs.len() as u64,
                 self,
             );
             if let C_KZG_RET::C_KZG_OK = res {
-                Ok(verified.assume_init())
+                Ok(verified.assume_init().into())
             } else {
-                Err(Error::CError(res))
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Same as [`verify_blob_kzg_proof_batch`](Self::verify_blob_kzg_proof_batch), but on
+    /// failure returns the indices of all blob/commitment/proof triples that do not verify.
+    ///
+    /// The triples are only verified one by one when the batch fails, so this is as fast as the
+    /// batch function when all proofs are valid.
+    pub fn verify_blob_kzg_proof_batch_detailed(
+        &self,
+        blobs: &[Blob],
+        commitments: &[KZGCommitment],
+        proofs: &[KZGProof],
+    ) -> Result<Result<(), Vec<usize>>, Error> {
+        if self
+            .verify_blob_kzg_proof_batch(blobs, commitments, proofs)?
+            .is_valid()
+        {
+            return Ok(Ok(()));
+        }
+        let mut invalid = Vec::new();
+        for (i, ((blob, commitment), proof)) in
+            blobs.iter().zip(commitments).zip(proofs).enumerate()
+        {
+            if !self
+                .verify_blob_kzg_proof(blob, commitment, proof)?
+                .is_valid()
+            {
+                invalid.push(i);
             }
         }
+        Ok(Err(invalid))
     }
 
+    /// Computes the `CELLS_PER_EXT_BLOB` cells of the extended blob and their proofs.
+    ///
+    /// The arrays are boxed because the cells alone take 256 KiB, which is too much to return on
+    /// the stack.
+    #[allow(clippy::type_complexity)]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, err)
+    )]
     pub fn compute_cells_and_kzg_proofs(
         &self,
         blob: &Blob,
@@ -456,110 +1289,407 @@ impl KZGSettings {
         ),
         Error,
     > {
-        let mut cells = [Cell::default(); CELLS_PER_EXT_BLOB];
-        let mut proofs = [KZGProof::default(); CELLS_PER_EXT_BLOB];
+        let mut cells = new_boxed_cells();
+        let mut proofs = Box::new([KZGProof::default(); CELLS_PER_EXT_BLOB]);
         unsafe {
             let res =
                 compute_cells_and_kzg_proofs(cells.as_mut_ptr(), proofs.as_mut_ptr(), blob, self);
             if let C_KZG_RET::C_KZG_OK = res {
-                Ok((Box::new(cells), Box::new(proofs)))
+                Ok((cells, proofs))
             } else {
-                Err(Error::CError(res))
+                Err(res.into())
             }
         }
     }
 
-    pub fn recover_cells_and_kzg_proofs(
-        &self,
-        cell_indices: &[u64],
-        cells: &[Cell],
-    ) -> Result<
-        (
-            Box<[Cell; CELLS_PER_EXT_BLOB]>,
-            Box<[KZGProof; CELLS_PER_EXT_BLOB]>,
+    /// Computes the `CELLS_PER_EXT_BLOB` cells of the extended blob without their proofs.
+    ///
+    /// The cells are the same as those of
+    /// [`compute_cells_and_kzg_proofs`](Self::compute_cells_and_kzg_proofs), but skipping the
+    /// proofs makes this much faster.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, err)
+    )]
+    pub fn compute_cells(&self, blob: &Blob) -> Result<Box<[Cell; CELLS_PER_EXT_BLOB]>, Error> {
+        let mut cells = new_boxed_cells();
+        unsafe {
+            let res =
+                compute_cells_and_kzg_proofs(cells.as_mut_ptr(), core::ptr::null_mut(), blob, self);
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok(cells)
+            } else {
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Computes the cell at `cell_index` of the extended blob and its proof.
+    ///
+    /// This is faster than [`compute_cells_and_kzg_proofs`](Self::compute_cells_and_kzg_proofs)
+    /// when only a few cells are needed, e.g. to answer a sampling request. To get all cells,
+    /// computing them at once is much faster.
+    pub fn compute_cell_and_kzg_proof(
+        &self,
+        blob: &Blob,
+        cell_index: CellIndex,
+    ) -> Result<(Cell, KZGProof), Error> {
+        let mut cell = MaybeUninit::<Cell>::uninit();
+        let mut proof = MaybeUninit::<KZGProof>::uninit();
+        unsafe {
+            let res = compute_cell_and_kzg_proof(
+                cell.as_mut_ptr(),
+                proof.as_mut_ptr(),
+                blob,
+                cell_index.into(),
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok((cell.assume_init(), proof.assume_init()))
+            } else {
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Recovers all cells of an extended blob, and their proofs, from at least half of them.
+    ///
+    /// The cell indices must not repeat.
+    #[allow(clippy::type_complexity)]
+    pub fn recover_cells_and_kzg_proofs(
+        &self,
+        cell_indices: &[CellIndex],
+        cells: &[Cell],
+    ) -> Result<
+        (
+            Box<[Cell; CELLS_PER_EXT_BLOB]>,
+            Box<[KZGProof; CELLS_PER_EXT_BLOB]>,
         ),
         Error,
     > {
-        if cell_indices.len() != cells.len() {
-            return Err(Error::MismatchLength(format!(
-                "There are {} cell indices and {} cells",
-                cell_indices.len(),
-                cells.len()
-            )));
-        }
-        let mut recovered_cells = [Cell::default(); CELLS_PER_EXT_BLOB];
-        let mut recovered_proofs = [KZGProof::default(); CELLS_PER_EXT_BLOB];
+        check_recovery_inputs(cell_indices, cells)?;
+        let mut recovered_cells = new_boxed_cells();
+        let mut recovered_proofs = Box::new([KZGProof::default(); CELLS_PER_EXT_BLOB]);
         unsafe {
             let res = recover_cells_and_kzg_proofs(
                 recovered_cells.as_mut_ptr(),
                 recovered_proofs.as_mut_ptr(),
-                cell_indices.as_ptr(),
+                cell_indices.as_ptr().cast(),
+                cells.as_ptr(),
+                cells.len() as u64,
+                self,
+            );
+            if let C_KZG_RET::C_KZG_OK = res {
+                Ok((recovered_cells, recovered_proofs))
+            } else {
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Same as [`recover_cells_and_kzg_proofs`](Self::recover_cells_and_kzg_proofs), but also
+    /// verifies the recovered cells and proofs against `commitment` before returning them.
+    ///
+    /// Fails with [`Error::InvalidKzgProof`] if they do not verify, e.g. because some of the
+    /// given cells do not belong to the blob of `commitment`.
+    #[allow(clippy::type_complexity)]
+    pub fn recover_and_verify(
+        &self,
+        commitment: &KZGCommitment,
+        cell_indices: &[CellIndex],
+        cells: &[Cell],
+    ) -> Result<
+        (
+            Box<[Cell; CELLS_PER_EXT_BLOB]>,
+            Box<[KZGProof; CELLS_PER_EXT_BLOB]>,
+        ),
+        Error,
+    > {
+        let (recovered_cells, recovered_proofs) =
+            self.recover_cells_and_kzg_proofs(cell_indices, cells)?;
+        let all_indices: Vec<CellIndex> = (0..CELLS_PER_EXT_BLOB as u64).map(CellIndex).collect();
+        if !self
+            .verify_cell_kzg_proof_batch(
+                &[*commitment; CELLS_PER_EXT_BLOB],
+                &all_indices,
+                &recovered_cells[..],
+                &recovered_proofs[..],
+            )?
+            .is_valid()
+        {
+            return Err(Error::InvalidKzgProof(
+                "Recovered cells and proofs do not verify against the commitment".into(),
+            ));
+        }
+        Ok((recovered_cells, recovered_proofs))
+    }
+
+    /// Recovers all cells of an extended blob from at least half of them, without computing
+    /// their proofs.
+    ///
+    /// This skips the proof computation of
+    /// [`recover_cells_and_kzg_proofs`](Self::recover_cells_and_kzg_proofs), which is most of its
+    /// cost.
+    pub fn recover_cells(
+        &self,
+        cell_indices: &[CellIndex],
+        cells: &[Cell],
+    ) -> Result<Box<[Cell; CELLS_PER_EXT_BLOB]>, Error> {
+        check_recovery_inputs(cell_indices, cells)?;
+        let mut recovered_cells = new_boxed_cells();
+        unsafe {
+            let res = recover_cells_and_kzg_proofs(
+                recovered_cells.as_mut_ptr(),
+                core::ptr::null_mut(),
+                cell_indices.as_ptr().cast(),
                 cells.as_ptr(),
                 cells.len() as u64,
                 self,
             );
             if let C_KZG_RET::C_KZG_OK = res {
-                Ok((Box::new(recovered_cells), Box::new(recovered_proofs)))
+                Ok(recovered_cells)
             } else {
-                Err(Error::CError(res))
+                Err(res.into())
             }
         }
     }
 
+    /// Verifies a single cell against its commitment and proof.
+    ///
+    /// Equivalent to calling [`verify_cell_kzg_proof_batch`](Self::verify_cell_kzg_proof_batch)
+    /// with one-element slices.
+    pub fn verify_cell_kzg_proof(
+        &self,
+        commitment: &KZGCommitment,
+        cell_index: CellIndex,
+        cell: &Cell,
+        proof: &KZGProof,
+    ) -> Result<VerificationResult, Error> {
+        self.verify_cell_kzg_proof_batch(
+            core::slice::from_ref(commitment),
+            &[cell_index],
+            core::slice::from_ref(cell),
+            core::slice::from_ref(proof),
+        )
+    }
+
+    /// Verifies many cells at once. The result is only valid if all of them verify.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(cells = cells.len()), err)
+    )]
     pub fn verify_cell_kzg_proof_batch(
         &self,
-        commitments_bytes: &[Bytes48],
-        cell_indices: &[u64],
+        commitments: &[KZGCommitment],
+        cell_indices: &[CellIndex],
         cells: &[Cell],
-        proofs_bytes: &[Bytes48],
-    ) -> Result<bool, Error> {
-        if cells.len() != commitments_bytes.len() {
+        proofs: &[KZGProof],
+    ) -> Result<VerificationResult, Error> {
+        if cells.len() != commitments.len() {
             return Err(Error::MismatchLength(format!(
                 "There are {} cells and {} commitments",
                 cells.len(),
-                commitments_bytes.len()
+                commitments.len()
             )));
         }
         if cells.len() != cell_indices.len() {
             return Err(Error::MismatchLength(format!(
-                "There are {} cells and {} column indices",
+                "There are {} cells and {} cell indices",
                 cells.len(),
                 cell_indices.len()
             )));
         }
-        if cells.len() != proofs_bytes.len() {
+        if cells.len() != proofs.len() {
             return Err(Error::MismatchLength(format!(
                 "There are {} cells and {} proofs",
                 cells.len(),
-                proofs_bytes.len()
+                proofs.len()
             )));
         }
         let mut verified: MaybeUninit<bool> = MaybeUninit::uninit();
         unsafe {
             let res = verify_cell_kzg_proof_batch(
                 verified.as_mut_ptr(),
-                commitments_bytes.as_ptr(),
-                cell_indices.as_ptr(),
+                commitments.as_ptr().cast(),
+                cell_indices.as_ptr().cast(),
                 cells.as_ptr(),
-                proofs_bytes.as_ptr(),
+                proofs.as_ptr().cast(),
                 cells.len() as u64,
                 self,
             );
             if let C_KZG_RET::C_KZG_OK = res {
-                Ok(verified.assume_init())
+                Ok(verified.assume_init().into())
             } else {
-                Err(Error::CError(res))
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Same as [`verify_cell_kzg_proof_batch`](Self::verify_cell_kzg_proof_batch), but returns
+    /// the index of the first cell whose proof does not verify, or `None` if all of them do.
+    ///
+    /// The whole batch is verified first, so this is only slower than the batch function when a
+    /// proof is invalid, in which case the cells are checked one by one.
+    ///
+    /// Slices of different lengths fail with [`Error::MismatchLength`] before anything is
+    /// verified.
+    pub fn find_invalid_cell_kzg_proof(
+        &self,
+        commitments: &[KZGCommitment],
+        cell_indices: &[CellIndex],
+        cells: &[Cell],
+        proofs: &[KZGProof],
+    ) -> Result<Option<usize>, Error> {
+        if self
+            .verify_cell_kzg_proof_batch(commitments, cell_indices, cells, proofs)?
+            .is_valid()
+        {
+            return Ok(None);
+        }
+        for i in 0..cells.len() {
+            if !self
+                .verify_cell_kzg_proof(&commitments[i], cell_indices[i], &cells[i], &proofs[i])?
+                .is_valid()
+            {
+                return Ok(Some(i));
             }
         }
+        Ok(None)
+    }
+
+    /// Same as [`verify_cell_kzg_proof_batch`](Self::verify_cell_kzg_proof_batch), but the
+    /// commitment of each cell is given by its index into `unique_commitments`.
+    ///
+    /// This avoids repeating the commitment of a blob for each of its cells. An index which is
+    /// out of range fails with [`Error::InvalidBatchElement`] for that cell.
+    pub fn verify_cell_kzg_proof_batch_grouped(
+        &self,
+        unique_commitments: &[KZGCommitment],
+        commitment_indices: &[u64],
+        cell_indices: &[CellIndex],
+        cells: &[Cell],
+        proofs: &[KZGProof],
+    ) -> Result<VerificationResult, Error> {
+        if cells.len() != commitment_indices.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} cells and {} commitment indices",
+                cells.len(),
+                commitment_indices.len()
+            )));
+        }
+        let commitments = commitment_indices
+            .iter()
+            .enumerate()
+            .map(|(index, &commitment_index)| {
+                usize::try_from(commitment_index)
+                    .ok()
+                    .and_then(|commitment_index| unique_commitments.get(commitment_index))
+                    .copied()
+                    .ok_or_else(|| Error::InvalidBatchElement {
+                        index,
+                        error: Box::new(Error::InvalidArgument),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.verify_cell_kzg_proof_batch(&commitments, cell_indices, cells, proofs)
     }
 }
 
 impl Drop for KZGSettings {
     fn drop(&mut self) {
+        #[cfg(feature = "zeroize")]
+        self.zeroize_tables();
         unsafe { free_trusted_setup(self) }
     }
 }
 
+/// Copies `len` bytes starting at `ptr` into a new allocation made with `malloc`, so that it can
+/// be released by `free_trusted_setup`. Returns null if `ptr` is null.
+///
+/// # Safety
+///
+/// If `ptr` is not null, it must be valid for reads of `len` bytes.
+unsafe fn clone_raw<T>(ptr: *const T, len: usize) -> *mut T {
+    if ptr.is_null() {
+        return core::ptr::null_mut();
+    }
+    let copy = libc::malloc(len).cast::<T>();
+    if copy.is_null() {
+        let layout = core::alloc::Layout::from_size_align(len, core::mem::align_of::<T>())
+            .expect("valid layout");
+        alloc::alloc::handle_alloc_error(layout);
+    }
+    core::ptr::copy_nonoverlapping(ptr.cast::<u8>(), copy.cast::<u8>(), len);
+    copy
+}
+
+/// Makes a deep copy of all C allocations, including the precomputed tables.
+///
+/// This is a plain memory copy and much cheaper than loading the trusted setup again. If the
+/// settings only need to be shared, prefer [`into_shared`](KZGSettings::into_shared).
+impl Clone for KZGSettings {
+    fn clone(&self) -> Self {
+        use core::mem::size_of;
+        let table_size = if self.tables.is_null() {
+            0
+        } else {
+            unsafe {
+                blst::blst_p1s_mult_wbits_precompute_sizeof(self.wbits, FIELD_ELEMENTS_PER_CELL)
+            }
+        };
+
+        // SAFETY: the array sizes match the allocations made in `load_trusted_setup`, and the
+        // columns and tables are only dereferenced if their pointer arrays are not null.
+        unsafe {
+            let clone_2d = |ptr: *mut *mut u8, row_size: usize| {
+                if ptr.is_null() {
+                    return core::ptr::null_mut();
+                }
+                let rows = clone_raw(ptr, CELLS_PER_EXT_BLOB * size_of::<*mut u8>());
+                for i in 0..CELLS_PER_EXT_BLOB {
+                    *rows.add(i) = clone_raw(*ptr.add(i), row_size);
+                }
+                rows
+            };
+
+            Self {
+                roots_of_unity: clone_raw(
+                    self.roots_of_unity,
+                    (FIELD_ELEMENTS_PER_EXT_BLOB + 1) * size_of::<fr_t>(),
+                ),
+                brp_roots_of_unity: clone_raw(
+                    self.brp_roots_of_unity,
+                    FIELD_ELEMENTS_PER_EXT_BLOB * size_of::<fr_t>(),
+                ),
+                reverse_roots_of_unity: clone_raw(
+                    self.reverse_roots_of_unity,
+                    (FIELD_ELEMENTS_PER_EXT_BLOB + 1) * size_of::<fr_t>(),
+                ),
+                g1_values_monomial: clone_raw(
+                    self.g1_values_monomial,
+                    NUM_G1_POINTS * size_of::<g1_t>(),
+                ),
+                g1_values_lagrange_brp: clone_raw(
+                    self.g1_values_lagrange_brp,
+                    NUM_G1_POINTS * size_of::<g1_t>(),
+                ),
+                g2_values_monomial: clone_raw(
+                    self.g2_values_monomial,
+                    NUM_G2_POINTS * size_of::<g2_t>(),
+                ),
+                x_ext_fft_columns: clone_2d(
+                    self.x_ext_fft_columns.cast(),
+                    FIELD_ELEMENTS_PER_CELL * size_of::<g1_t>(),
+                )
+                .cast(),
+                tables: clone_2d(self.tables.cast(), table_size).cast(),
+                wbits: self.wbits,
+                scratch_size: self.scratch_size,
+            }
+        }
+    }
+}
+
 impl Blob {
     /// Creates a new blob from a byte array.
     pub const fn new(bytes: [u8; BYTES_PER_BLOB]) -> Self {
@@ -568,21 +1698,79 @@ impl Blob {
 
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         if bytes.len() != BYTES_PER_BLOB {
-            return Err(Error::InvalidBytesLength(format!(
-                "Invalid byte length. Expected {} got {}",
-                BYTES_PER_BLOB,
-                bytes.len(),
-            )));
+            return Err(Error::InvalidBlobLength {
+                expected: BYTES_PER_BLOB,
+                got: bytes.len(),
+            });
         }
         let mut new_bytes = [0; BYTES_PER_BLOB];
         new_bytes.copy_from_slice(bytes);
         Ok(Self::new(new_bytes))
     }
 
+    /// Reinterprets `bytes` as a blob without copying them, unlike
+    /// [`from_bytes`](Self::from_bytes).
+    ///
+    /// The slice must have exactly `BYTES_PER_BLOB` bytes. There is no alignment requirement, as
+    /// a blob is only a byte array and has an alignment of 1.
+    pub fn from_bytes_ref(bytes: &[u8]) -> Result<&Self, Error> {
+        let bytes: &[u8; BYTES_PER_BLOB] =
+            bytes.try_into().map_err(|_| Error::InvalidBlobLength {
+                expected: BYTES_PER_BLOB,
+                got: bytes.len(),
+            })?;
+        const _: () = assert!(core::mem::align_of::<Blob>() == 1);
+        // SAFETY: `Blob` is a `repr(C)` struct whose only field is a `[u8; BYTES_PER_BLOB]`, so
+        // it has the same size and alignment as the array.
+        Ok(unsafe { &*(bytes as *const [u8; BYTES_PER_BLOB]).cast::<Self>() })
+    }
+
     pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
         Self::from_bytes(&hex_to_bytes(hex_str)?)
     }
 
+    /// Creates a new blob from exactly `FIELD_ELEMENTS_PER_BLOB` field elements, each of which
+    /// must be canonical.
+    pub fn from_field_elements(elements: &[Bytes32]) -> Result<Self, Error> {
+        if elements.len() != FIELD_ELEMENTS_PER_BLOB {
+            return Err(Error::InvalidBytesLength(format!(
+                "Invalid number of field elements. Expected {} got {}",
+                FIELD_ELEMENTS_PER_BLOB,
+                elements.len(),
+            )));
+        }
+        let mut bytes = [0; BYTES_PER_BLOB];
+        for (index, (chunk, element)) in bytes
+            .chunks_exact_mut(BYTES_PER_FIELD_ELEMENT)
+            .zip(elements)
+            .enumerate()
+        {
+            if !is_valid_field_element(element) {
+                return Err(Error::NonCanonicalFieldElement(index));
+            }
+            chunk.copy_from_slice(&element.bytes);
+        }
+        Ok(Self::new(bytes))
+    }
+
+    /// Returns an iterator over the `FIELD_ELEMENTS_PER_BLOB` field elements of the blob.
+    ///
+    /// The elements are not checked to be canonical, see [`KZGSettings::validate_blob`].
+    pub fn field_elements(&self) -> impl ExactSizeIterator<Item = &Bytes32> + '_ {
+        self.bytes
+            .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+            .map(|chunk| {
+                // SAFETY: `Bytes32` is a `repr(C)` struct whose only field is a `[u8; 32]`, so it has
+                // the same size and alignment as each chunk.
+                unsafe { &*chunk.as_ptr().cast::<Bytes32>() }
+            })
+    }
+
+    /// Converts the bytes to a hex string with the 0x prefix.
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.bytes))
+    }
+
     pub fn into_inner(self) -> [u8; BYTES_PER_BLOB] {
         self.bytes
     }
@@ -616,6 +1804,11 @@ impl Bytes32 {
     pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
         Self::from_bytes(&hex_to_bytes(hex_str)?)
     }
+
+    /// Converts the bytes to a hex string with the 0x prefix.
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.bytes))
+    }
 }
 
 impl Bytes48 {
@@ -641,6 +1834,11 @@ impl Bytes48 {
         Self::from_bytes(&hex_to_bytes(hex_str)?)
     }
 
+    /// Converts the bytes to a hex string with the 0x prefix.
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.bytes))
+    }
+
     pub fn into_inner(self) -> [u8; 48] {
         self.bytes
     }
@@ -664,9 +1862,36 @@ impl KZGProof {
         Bytes48 { bytes: self.bytes }
     }
 
+    /// Reinterprets the bytes as the untyped `Bytes48` the C functions take.
+    fn as_bytes48(&self) -> &Bytes48 {
+        // SAFETY: `Self` is a `repr(transparent)` wrapper of `[u8; 48]`, and `Bytes48` is a
+        // `repr(C)` struct whose only field is a `[u8; 48]`.
+        unsafe { &*(self as *const Self).cast::<Bytes48>() }
+    }
+
     pub fn as_hex_string(&self) -> String {
         hex::encode(self.bytes)
     }
+
+    pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
+        Self::from_bytes(&hex_to_bytes(hex_str)?)
+    }
+
+    /// Converts the bytes to a hex string with the 0x prefix.
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.bytes))
+    }
+
+    /// Verifies that this is the proof of `blob` for `commitment`. Same as
+    /// [`KZGSettings::verify_blob_kzg_proof`].
+    pub fn verify(
+        &self,
+        blob: &Blob,
+        commitment: &KZGCommitment,
+        kzg_settings: &KZGSettings,
+    ) -> Result<VerificationResult, Error> {
+        kzg_settings.verify_blob_kzg_proof(blob, commitment, self)
+    }
 }
 
 impl KZGCommitment {
@@ -687,9 +1912,36 @@ impl KZGCommitment {
         Bytes48 { bytes: self.bytes }
     }
 
+    /// Reinterprets the bytes as the untyped `Bytes48` the C functions take.
+    fn as_bytes48(&self) -> &Bytes48 {
+        // SAFETY: `Self` is a `repr(transparent)` wrapper of `[u8; 48]`, and `Bytes48` is a
+        // `repr(C)` struct whose only field is a `[u8; 48]`.
+        unsafe { &*(self as *const Self).cast::<Bytes48>() }
+    }
+
     pub fn as_hex_string(&self) -> String {
         hex::encode(self.bytes)
     }
+
+    pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
+        Self::from_bytes(&hex_to_bytes(hex_str)?)
+    }
+
+    /// Converts the bytes to a hex string with the 0x prefix.
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.bytes))
+    }
+
+    /// Verifies that `proof` is the proof of `blob` for this commitment. Same as
+    /// [`KZGSettings::verify_blob_kzg_proof`].
+    pub fn verify_blob(
+        &self,
+        blob: &Blob,
+        proof: &KZGProof,
+        kzg_settings: &KZGSettings,
+    ) -> Result<VerificationResult, Error> {
+        kzg_settings.verify_blob_kzg_proof(blob, self, proof)
+    }
 }
 
 impl Cell {
@@ -714,9 +1966,46 @@ impl Cell {
         self.bytes
     }
 
+    /// Creates a new cell from its field elements, each of which must be canonical.
+    pub fn from_field_elements(
+        elements: &[Bytes32; FIELD_ELEMENTS_PER_CELL],
+    ) -> Result<Self, Error> {
+        let mut bytes = [0; BYTES_PER_CELL];
+        for (index, (chunk, element)) in bytes
+            .chunks_exact_mut(BYTES_PER_FIELD_ELEMENT)
+            .zip(elements)
+            .enumerate()
+        {
+            if !is_valid_field_element(element) {
+                return Err(Error::NonCanonicalFieldElement(index));
+            }
+            chunk.copy_from_slice(&element.bytes);
+        }
+        Ok(Self::new(bytes))
+    }
+
+    /// Returns an iterator over the `FIELD_ELEMENTS_PER_CELL` field elements of the cell, which
+    /// are the evaluations of the blob polynomial at the cell's points.
+    ///
+    /// The elements are not checked to be canonical.
+    pub fn field_elements(&self) -> impl ExactSizeIterator<Item = &Bytes32> + '_ {
+        self.bytes
+            .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+            .map(|chunk| {
+                // SAFETY: `Bytes32` is a `repr(C)` struct whose only field is a `[u8; 32]`, so it has
+                // the same size and alignment as each chunk.
+                unsafe { &*chunk.as_ptr().cast::<Bytes32>() }
+            })
+    }
+
     pub fn from_hex(hex_str: &str) -> Result<Self, Error> {
         Self::from_bytes(&hex_to_bytes(hex_str)?)
     }
+
+    /// Converts the bytes to a hex string with the 0x prefix.
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.bytes))
+    }
 }
 
 impl From<[u8; BYTES_PER_COMMITMENT]> for KZGCommitment {
@@ -731,6 +2020,42 @@ impl From<[u8; BYTES_PER_PROOF]> for KZGProof {
     }
 }
 
+impl From<KZGCommitment> for [u8; BYTES_PER_COMMITMENT] {
+    fn from(value: KZGCommitment) -> Self {
+        value.bytes
+    }
+}
+
+impl From<KZGProof> for [u8; BYTES_PER_PROOF] {
+    fn from(value: KZGProof) -> Self {
+        value.bytes
+    }
+}
+
+impl From<KZGCommitment> for Bytes48 {
+    fn from(value: KZGCommitment) -> Self {
+        value.to_bytes()
+    }
+}
+
+impl From<KZGProof> for Bytes48 {
+    fn from(value: KZGProof) -> Self {
+        value.to_bytes()
+    }
+}
+
+impl From<Bytes48> for KZGCommitment {
+    fn from(value: Bytes48) -> Self {
+        Self { bytes: value.bytes }
+    }
+}
+
+impl From<Bytes48> for KZGProof {
+    fn from(value: Bytes48) -> Self {
+        Self { bytes: value.bytes }
+    }
+}
+
 impl From<[u8; BYTES_PER_BLOB]> for Blob {
     fn from(value: [u8; BYTES_PER_BLOB]) -> Self {
         Self { bytes: value }
@@ -814,183 +2139,1432 @@ impl Deref for KZGCommitment {
     }
 }
 
-impl Default for Bytes32 {
-    fn default() -> Self {
-        Bytes32 { bytes: [0; 32] }
+impl TryFrom<&[u8]> for Blob {
+    type Error = Error;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
     }
 }
 
-impl Default for Bytes48 {
-    fn default() -> Self {
-        Bytes48 { bytes: [0; 48] }
+impl TryFrom<Vec<u8>> for Blob {
+    type Error = Error;
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_bytes(&value)
     }
 }
 
-impl Default for KZGCommitment {
-    fn default() -> Self {
-        KZGCommitment {
-            bytes: [0; BYTES_PER_COMMITMENT],
-        }
+impl TryFrom<&[u8]> for Bytes32 {
+    type Error = Error;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
     }
 }
 
-impl Default for KZGProof {
-    fn default() -> Self {
-        KZGProof {
-            bytes: [0; BYTES_PER_PROOF],
-        }
+impl TryFrom<Vec<u8>> for Bytes32 {
+    type Error = Error;
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_bytes(&value)
     }
 }
 
-impl Default for Blob {
-    fn default() -> Self {
-        Blob {
-            bytes: [0; BYTES_PER_BLOB],
-        }
+impl TryFrom<&[u8]> for Bytes48 {
+    type Error = Error;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
     }
 }
 
-impl Default for Cell {
-    fn default() -> Self {
-        Cell {
-            bytes: [0; BYTES_PER_CELL],
-        }
+impl TryFrom<Vec<u8>> for Bytes48 {
+    type Error = Error;
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_bytes(&value)
     }
 }
 
-impl Arbitrary<'_> for Bytes32 {
-    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
-        let mut bytes = [0u8; 32];
-        u.fill_buffer(&mut bytes)?;
-        Ok(Bytes32::from(bytes))
+impl TryFrom<&[u8]> for Cell {
+    type Error = Error;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
     }
 }
 
-impl Arbitrary<'_> for Bytes48 {
-    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
-        let mut bytes = [0u8; 48];
-        u.fill_buffer(&mut bytes)?;
-        Ok(Bytes48::from(bytes))
+impl TryFrom<Vec<u8>> for Cell {
+    type Error = Error;
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_bytes(&value)
     }
 }
 
-impl Arbitrary<'_> for Blob {
-    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
-        let mut bytes = [0u8; BYTES_PER_BLOB];
-        u.fill_buffer(&mut bytes)?;
-        Ok(Blob::from(bytes))
+impl TryFrom<&[u8]> for KZGCommitment {
+    type Error = Error;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
     }
 }
 
-impl Arbitrary<'_> for Cell {
-    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
-        let mut bytes = [0u8; BYTES_PER_CELL];
-        u.fill_buffer(&mut bytes)?;
-        Ok(Cell::new(bytes))
+impl TryFrom<Vec<u8>> for KZGCommitment {
+    type Error = Error;
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_bytes(&value)
+    }
+}
+
+impl TryFrom<&[u8]> for KZGProof {
+    type Error = Error;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(value)
+    }
+}
+
+impl TryFrom<Vec<u8>> for KZGProof {
+    type Error = Error;
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_bytes(&value)
+    }
+}
+
+impl FromStr for Blob {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl fmt::Display for Blob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl FromStr for Bytes32 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl fmt::Display for Bytes32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
     }
 }
 
-/// Safety: The memory for `roots_of_unity` and `g1_values` and `g2_values` are only freed on
-/// calling `free_trusted_setup` which only happens when we drop the struct.
-unsafe impl Sync for KZGSettings {}
-unsafe impl Send for KZGSettings {}
+impl FromStr for Bytes48 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl fmt::Display for Bytes48 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl FromStr for Cell {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl FromStr for KZGCommitment {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl fmt::Display for KZGCommitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl FromStr for KZGProof {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl fmt::Display for KZGProof {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+/// Writes `bytes` as `name(0x...)`, with only the first and last `n` bytes in hex.
+fn fmt_truncated_hex(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    bytes: &[u8],
+    n: usize,
+) -> fmt::Result {
+    write!(f, "{name}(0x")?;
+    bytes[..n]
+        .iter()
+        .try_for_each(|byte| write!(f, "{byte:02x}"))?;
+    f.write_str("...")?;
+    bytes[bytes.len() - n..]
+        .iter()
+        .try_for_each(|byte| write!(f, "{byte:02x}"))?;
+    f.write_str(")")
+}
+
+macro_rules! impl_truncated_debug {
+    ($($type:ty => ($name:literal, $n:literal)),* $(,)?) => {
+        $(
+            impl fmt::Debug for $type {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt_truncated_hex(f, $name, &self.bytes, $n)
+                }
+            }
+        )*
+    };
+}
+
+impl_truncated_debug! {
+    Blob => ("Blob", 8),
+    Bytes32 => ("Bytes32", 4),
+    Bytes48 => ("Bytes48", 4),
+    Cell => ("Cell", 4),
+    KZGCommitment => ("KzgCommitment", 4),
+    KZGProof => ("KzgProof", 4),
+}
+
+impl AsMut<[u8]> for Blob {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+impl AsRef<[u8]> for Bytes32 {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl AsMut<[u8]> for Bytes32 {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+impl AsRef<[u8]> for Bytes48 {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl AsMut<[u8]> for Bytes48 {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+impl AsRef<[u8]> for Cell {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl AsMut<[u8]> for Cell {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+impl AsRef<[u8]> for KZGCommitment {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl AsMut<[u8]> for KZGCommitment {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+impl AsRef<[u8]> for KZGProof {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl AsMut<[u8]> for KZGProof {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Bytes32 {
+    fn default() -> Self {
+        Bytes32 { bytes: [0; 32] }
+    }
+}
+
+impl Default for Bytes48 {
+    fn default() -> Self {
+        Bytes48 { bytes: [0; 48] }
+    }
+}
+
+impl Default for KZGCommitment {
+    fn default() -> Self {
+        KZGCommitment {
+            bytes: [0; BYTES_PER_COMMITMENT],
+        }
+    }
+}
+
+impl Default for KZGProof {
+    fn default() -> Self {
+        KZGProof {
+            bytes: [0; BYTES_PER_PROOF],
+        }
+    }
+}
+
+impl Default for Blob {
+    fn default() -> Self {
+        Blob {
+            bytes: [0; BYTES_PER_BLOB],
+        }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell {
+            bytes: [0; BYTES_PER_CELL],
+        }
+    }
+}
+
+impl PartialOrd for Bytes32 {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Bytes32 {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.bytes.cmp(&other.bytes)
+    }
+}
+
+impl PartialOrd for Bytes48 {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Bytes48 {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.bytes.cmp(&other.bytes)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for Bytes32 {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.bytes.ct_eq(&other.bytes)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for Bytes48 {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.bytes.ct_eq(&other.bytes)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for KZGCommitment {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.bytes.ct_eq(&other.bytes)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for KZGProof {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.bytes.ct_eq(&other.bytes)
+    }
+}
+
+/// With the `subtle` feature, commitments are compared in constant time.
+#[cfg(feature = "subtle")]
+impl PartialEq for KZGCommitment {
+    fn eq(&self, other: &Self) -> bool {
+        subtle::ConstantTimeEq::ct_eq(self, other).into()
+    }
+}
+
+/// With the `subtle` feature, proofs are compared in constant time.
+#[cfg(feature = "subtle")]
+impl PartialEq for KZGProof {
+    fn eq(&self, other: &Self) -> bool {
+        subtle::ConstantTimeEq::ct_eq(self, other).into()
+    }
+}
+
+impl Arbitrary<'_> for Bytes32 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut bytes = [0u8; 32];
+        u.fill_buffer(&mut bytes)?;
+        Ok(Bytes32::from(bytes))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (32, Some(32))
+    }
+}
+
+impl Arbitrary<'_> for Bytes48 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut bytes = [0u8; 48];
+        u.fill_buffer(&mut bytes)?;
+        Ok(Bytes48::from(bytes))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (48, Some(48))
+    }
+}
+
+impl Arbitrary<'_> for KZGCommitment {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        Ok(Bytes48::arbitrary(u)?.into())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Bytes48::size_hint(depth)
+    }
+}
+
+impl Arbitrary<'_> for KZGProof {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        Ok(Bytes48::arbitrary(u)?.into())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Bytes48::size_hint(depth)
+    }
+}
+
+impl Arbitrary<'_> for Blob {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut bytes = [0u8; BYTES_PER_BLOB];
+        u.fill_buffer(&mut bytes)?;
+        Ok(Blob::from(bytes))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (BYTES_PER_BLOB, Some(BYTES_PER_BLOB))
+    }
+}
+
+impl Arbitrary<'_> for Cell {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        let mut bytes = [0u8; BYTES_PER_CELL];
+        u.fill_buffer(&mut bytes)?;
+        Ok(Cell::new(bytes))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (BYTES_PER_CELL, Some(BYTES_PER_CELL))
+    }
+}
+
+/// Safety: The memory behind the raw pointers (roots of unity, G1/G2 points, FK20 columns and
+/// precomputed tables) is allocated by the C library when the settings are loaded and is only
+/// freed on calling `free_trusted_setup`, which only happens when we drop the struct. None of the
+/// C functions taking a `*const KZGSettings` write through it, so after initialization the data
+/// is read-only and can be shared between and moved across threads.
+unsafe impl Sync for KZGSettings {}
+unsafe impl Send for KZGSettings {}
+
+#[cfg(test)]
+#[allow(unused_imports, dead_code)]
+mod tests {
+    use super::*;
+    use rand::{rngs::ThreadRng, Rng};
+    use std::{fs, path::PathBuf};
+    use test_formats::{
+        blob_to_kzg_commitment_test, compute_blob_kzg_proof, compute_cells_and_kzg_proofs,
+        compute_kzg_proof, recover_cells_and_kzg_proofs, verify_blob_kzg_proof,
+        verify_blob_kzg_proof_batch, verify_cell_kzg_proof_batch, verify_kzg_proof,
+    };
+
+    pub(super) fn generate_random_blob(rng: &mut ThreadRng) -> Blob {
+        let mut arr = [0u8; BYTES_PER_BLOB];
+        rng.fill(&mut arr[..]);
+        // Ensure that the blob is canonical by ensuring that
+        // each field element contained in the blob is < BLS_MODULUS
+        for i in 0..FIELD_ELEMENTS_PER_BLOB {
+            arr[i * BYTES_PER_FIELD_ELEMENT] = 0;
+        }
+        arr.into()
+    }
+
+    fn test_simple(trusted_setup_file: &Path) {
+        let mut rng = rand::thread_rng();
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let num_blobs: usize = rng.gen_range(1..16);
+        let mut blobs: Vec<Blob> = (0..num_blobs)
+            .map(|_| generate_random_blob(&mut rng))
+            .collect();
+
+        let commitments: Vec<KZGCommitment> = blobs
+            .iter()
+            .map(|blob| kzg_settings.blob_to_kzg_commitment(blob).unwrap())
+            .collect();
+
+        let proofs: Vec<KZGProof> = blobs
+            .iter()
+            .zip(commitments.iter())
+            .map(|(blob, commitment)| {
+                kzg_settings
+                    .compute_blob_kzg_proof(blob, commitment)
+                    .unwrap()
+            })
+            .collect();
+
+        assert!(kzg_settings
+            .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
+            .unwrap()
+            .is_valid());
+
+        blobs.pop();
+
+        let error = kzg_settings
+            .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
+            .unwrap_err();
+        assert!(matches!(error, Error::MismatchLength(_)));
+
+        let incorrect_blob = generate_random_blob(&mut rng);
+        blobs.push(incorrect_blob);
+
+        assert!(!kzg_settings
+            .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
+            .unwrap()
+            .is_valid());
+    }
+
+    #[test]
+    fn test_end_to_end() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        test_simple(trusted_setup_file);
+    }
+
+    #[test]
+    fn test_commitment_and_proof_traits() {
+        use std::collections::HashSet;
+
+        let low = [0u8; BYTES_PER_COMMITMENT];
+        let mut high = [0u8; BYTES_PER_COMMITMENT];
+        high[BYTES_PER_COMMITMENT - 1] = 1;
+
+        let commitments: HashSet<KZGCommitment> =
+            [low.into(), high.into(), low.into()].into_iter().collect();
+        assert_eq!(commitments.len(), 2);
+        assert_eq!(KZGCommitment::from(low), KZGCommitment::from(low));
+        assert_ne!(KZGCommitment::from(low), KZGCommitment::from(high));
+        assert!(KZGCommitment::from(low) < KZGCommitment::from(high));
+
+        let proofs: HashSet<KZGProof> =
+            [low.into(), high.into(), high.into()].into_iter().collect();
+        assert_eq!(proofs.len(), 2);
+        assert!(KZGProof::from(high) > KZGProof::from(low));
+
+        assert!(Bytes48::from(low) < Bytes48::from(high));
+        assert!(Bytes32::from([0; 32]) < Bytes32::from([1; 32]));
+
+        let bytes: HashSet<Bytes48> = [low.into(), low.into()].into_iter().collect();
+        assert_eq!(bytes.len(), 1);
+        let scalars: HashSet<Bytes32> = [[0; 32].into(), [1; 32].into(), [0; 32].into()]
+            .into_iter()
+            .collect();
+        assert_eq!(scalars.len(), 2);
+        let cells: HashSet<Cell> = [Cell::default(), Cell::default()].into_iter().collect();
+        assert_eq!(cells.len(), 1);
+    }
+
+    #[test]
+    fn test_byte_order_is_lexicographic() {
+        let mut values = Vec::new();
+        for (index, byte) in [(0, 2), (0, 1), (47, 0xff), (1, 0), (0, 0), (47, 1)] {
+            let mut bytes = [0u8; BYTES_PER_COMMITMENT];
+            bytes[index] = byte;
+            values.push(bytes);
+        }
+        let mut expected: Vec<Vec<u8>> = values.iter().map(|bytes| bytes.to_vec()).collect();
+        expected.sort();
+
+        let mut commitments: Vec<KZGCommitment> = values.iter().map(|&b| b.into()).collect();
+        commitments.sort();
+        let sorted: Vec<Vec<u8>> = commitments.iter().map(|c| c.to_vec()).collect();
+        assert_eq!(sorted, expected);
+        assert!(commitments
+            .binary_search(&KZGCommitment::from(values[2]))
+            .is_ok());
+
+        let mut proofs: Vec<KZGProof> = values.iter().map(|&b| b.into()).collect();
+        proofs.sort();
+        assert_eq!(
+            proofs.iter().map(|p| p.to_vec()).collect::<Vec<_>>(),
+            expected
+        );
+
+        let mut bytes: Vec<Bytes48> = values.iter().map(|&b| b.into()).collect();
+        bytes.sort();
+        assert_eq!(
+            bytes.iter().map(|b| b.to_vec()).collect::<Vec<_>>(),
+            expected
+        );
+
+        let mut scalars: Vec<Bytes32> = values
+            .iter()
+            .map(|b| Bytes32::new(b[..32].try_into().unwrap()))
+            .collect();
+        scalars.sort();
+        let mut expected: Vec<Vec<u8>> = values.iter().map(|b| b[..32].to_vec()).collect();
+        expected.sort();
+        assert_eq!(
+            scalars.iter().map(|b| b.to_vec()).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_blob_from_bytes_ref() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        // Start at an odd offset to check that no alignment is needed.
+        let mut buffer = vec![0u8; BYTES_PER_BLOB + 1];
+        buffer[1..].copy_from_slice(blob.as_ref());
+
+        let blob_ref = Blob::from_bytes_ref(&buffer[1..]).unwrap();
+        assert_eq!(blob_ref.as_ref().as_ptr(), buffer[1..].as_ptr());
+        assert_eq!(blob_ref, &blob);
+        assert_eq!(
+            kzg_settings.blob_to_kzg_commitment(blob_ref).unwrap(),
+            kzg_settings.blob_to_kzg_commitment(&blob).unwrap()
+        );
+
+        for len in [0, BYTES_PER_BLOB - 1, BYTES_PER_BLOB + 1] {
+            assert!(matches!(
+                Blob::from_bytes_ref(&buffer[..len]),
+                Err(Error::InvalidBlobLength { got, .. }) if got == len
+            ));
+        }
+    }
+
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::Unstructured;
+
+        fn check<T: for<'a> Arbitrary<'a> + Default + PartialEq + fmt::Debug>(size: usize) {
+            assert_eq!(T::size_hint(0), (size, Some(size)));
+            let zeros = vec![0u8; size + 1];
+            let mut u = Unstructured::new(&zeros);
+            assert_eq!(T::arbitrary(&mut u).unwrap(), T::default());
+            assert_eq!(u.len(), 1);
+        }
+
+        check::<Blob>(BYTES_PER_BLOB);
+        check::<Cell>(BYTES_PER_CELL);
+        check::<Bytes32>(32);
+        check::<Bytes48>(48);
+        check::<KZGCommitment>(BYTES_PER_COMMITMENT);
+        check::<KZGProof>(BYTES_PER_PROOF);
+    }
+
+    #[test]
+    fn test_byte_conversions() {
+        let blob = Blob::try_from(vec![1u8; BYTES_PER_BLOB]).unwrap();
+        assert_eq!(blob.as_ref(), &[1u8; BYTES_PER_BLOB][..]);
+        let error = Blob::try_from(&[1u8; BYTES_PER_BLOB - 1][..]).unwrap_err();
+        assert!(matches!(error, Error::InvalidBlobLength { .. }));
+
+        let mut cell = Cell::try_from(&[2u8; BYTES_PER_CELL][..]).unwrap();
+        cell.as_mut()[0] = 3;
+        assert_eq!(cell.as_ref()[..2], [3, 2]);
+        assert!(Cell::try_from(vec![2u8; BYTES_PER_CELL + 1]).is_err());
+
+        let mut bytes32 = Bytes32::try_from(&[4u8; 32][..]).unwrap();
+        bytes32.as_mut()[31] = 5;
+        assert_eq!(AsRef::<[u8]>::as_ref(&bytes32)[30..], [4, 5]);
+        assert!(Bytes32::try_from(vec![4u8; 48]).is_err());
+
+        let bytes48 = Bytes48::try_from(vec![6u8; 48]).unwrap();
+        assert_eq!(AsRef::<[u8]>::as_ref(&bytes48), &[6u8; 48][..]);
+        assert!(Bytes48::try_from(&[6u8; 32][..]).is_err());
+
+        let commitment = KZGCommitment::try_from(&[7u8; BYTES_PER_COMMITMENT][..]).unwrap();
+        assert_eq!(commitment.as_ref(), &[7u8; BYTES_PER_COMMITMENT][..]);
+        let error = KZGCommitment::try_from(vec![7u8; 47]).unwrap_err();
+        assert!(matches!(error, Error::InvalidKzgCommitment(_)));
+
+        let mut proof = KZGProof::try_from(vec![8u8; BYTES_PER_PROOF]).unwrap();
+        proof.as_mut()[0] = 9;
+        assert_eq!(proof.as_ref()[..2], [9, 8]);
+        let error = KZGProof::try_from(&[8u8; 49][..]).unwrap_err();
+        assert!(matches!(error, Error::InvalidKzgProof(_)));
+    }
+
+    #[test]
+    fn test_try_from_lengths() {
+        fn check<T>(len: usize)
+        where
+            T: for<'a> TryFrom<&'a [u8], Error = Error> + TryFrom<Vec<u8>, Error = Error>,
+            T: AsRef<[u8]>,
+        {
+            let bytes: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            assert_eq!(T::try_from(&bytes[..]).ok().unwrap().as_ref(), &bytes[..]);
+            assert_eq!(
+                T::try_from(bytes.clone()).ok().unwrap().as_ref(),
+                &bytes[..]
+            );
+
+            for wrong_len in [0, len - 1, len + 1] {
+                assert!(T::try_from(&vec![0u8; wrong_len][..]).is_err());
+                assert!(T::try_from(vec![0u8; wrong_len]).is_err());
+            }
+        }
+
+        check::<Blob>(BYTES_PER_BLOB);
+        check::<Bytes32>(32);
+        check::<Bytes48>(48);
+        check::<Cell>(BYTES_PER_CELL);
+        check::<KZGCommitment>(BYTES_PER_COMMITMENT);
+        check::<KZGProof>(BYTES_PER_PROOF);
+    }
+
+    #[test]
+    fn test_verify_blob_kzg_proof_batch_detailed() {
+        let mut rng = rand::thread_rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blobs: Vec<Blob> = (0..5).map(|_| generate_random_blob(&mut rng)).collect();
+        let commitments: Vec<KZGCommitment> = blobs
+            .iter()
+            .map(|blob| kzg_settings.blob_to_kzg_commitment(blob).unwrap())
+            .collect();
+        let mut proofs: Vec<KZGProof> = blobs
+            .iter()
+            .zip(&commitments)
+            .map(|(blob, commitment)| {
+                kzg_settings
+                    .compute_blob_kzg_proof(blob, commitment)
+                    .unwrap()
+            })
+            .collect();
+
+        assert_eq!(
+            kzg_settings
+                .verify_blob_kzg_proof_batch_detailed(&blobs, &commitments, &proofs)
+                .unwrap(),
+            Ok(())
+        );
+
+        proofs.swap(1, 3);
+        assert_eq!(
+            kzg_settings
+                .verify_blob_kzg_proof_batch_detailed(&blobs, &commitments, &proofs)
+                .unwrap(),
+            Err(vec![1, 3])
+        );
+        assert!(kzg_settings
+            .verify_blob_kzg_proof_batch_detailed(&blobs, &commitments[1..], &proofs)
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_blob() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        assert!(kzg_settings
+            .validate_blob(&Blob::new([0; BYTES_PER_BLOB]))
+            .is_ok());
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        assert!(kzg_settings.validate_blob(&blob).is_ok());
+
+        let modulus =
+            hex::decode("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")
+                .unwrap();
+        let mut bytes = blob.into_inner();
+        bytes[7 * BYTES_PER_FIELD_ELEMENT..8 * BYTES_PER_FIELD_ELEMENT].copy_from_slice(&modulus);
+        bytes[9 * BYTES_PER_FIELD_ELEMENT] = 0xff;
+        let blob = Blob::new(bytes);
+        assert!(matches!(
+            kzg_settings.validate_blob(&blob),
+            Err(Error::NonCanonicalFieldElement(7))
+        ));
+        assert!(kzg_settings.blob_to_kzg_commitment(&blob).is_err());
+    }
+
+    #[test]
+    fn test_is_valid_field_element() {
+        let modulus =
+            Bytes32::from_hex("0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")
+                .unwrap();
+        let mut below_modulus = modulus;
+        below_modulus.bytes[31] = 0;
+        let mut above_modulus = modulus;
+        above_modulus.bytes[16] = 0xff;
+        assert!(is_valid_field_element(&Bytes32::new([0; 32])));
+        assert!(is_valid_field_element(&below_modulus));
+        assert!(!is_valid_field_element(&modulus));
+        assert!(!is_valid_field_element(&above_modulus));
+        assert!(!is_valid_field_element(&Bytes32::new([0xff; 32])));
+
+        // Agree with blst on random values around the size of the modulus.
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let mut bytes = [0u8; 32];
+            rng.fill(&mut bytes);
+            bytes[0] &= 0x7f;
+            let mut scalar = blst::blst_scalar::default();
+            let expected = unsafe {
+                blst::blst_scalar_from_bendian(&mut scalar, bytes.as_ptr());
+                blst::blst_scalar_fr_check(&scalar)
+            };
+            assert_eq!(is_valid_field_element(&Bytes32::new(bytes)), expected);
+        }
+
+        let blob = generate_random_blob(&mut rng);
+        assert!(validate_blob_field_elements(&blob).is_ok());
+        let mut bytes = blob.into_inner();
+        bytes[2 * BYTES_PER_FIELD_ELEMENT..3 * BYTES_PER_FIELD_ELEMENT]
+            .copy_from_slice(&modulus.bytes);
+        assert_eq!(
+            validate_blob_field_elements(&Blob::new(bytes)),
+            Err((2, modulus))
+        );
+    }
+
+    #[test]
+    fn test_blob_field_elements() {
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let elements: Vec<Bytes32> = blob.field_elements().copied().collect();
+        assert_eq!(elements.len(), FIELD_ELEMENTS_PER_BLOB);
+        assert_eq!(
+            &elements[3].bytes[..],
+            &blob.as_ref()[3 * BYTES_PER_FIELD_ELEMENT..4 * BYTES_PER_FIELD_ELEMENT]
+        );
+        assert_eq!(Blob::from_field_elements(&elements).unwrap(), blob);
+
+        assert!(matches!(
+            Blob::from_field_elements(&elements[1..]),
+            Err(Error::InvalidBytesLength(_))
+        ));
+        let mut elements = elements;
+        elements[5] = Bytes32::new([0xff; 32]);
+        assert!(matches!(
+            Blob::from_field_elements(&elements),
+            Err(Error::NonCanonicalFieldElement(5))
+        ));
+    }
+
+    #[test]
+    fn test_cell_field_elements() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let cells = kzg_settings.compute_cells(&blob).unwrap();
+        let cell = &cells[5];
+
+        assert_eq!(cell.field_elements().len(), FIELD_ELEMENTS_PER_CELL);
+        let elements: Vec<Bytes32> = cell.field_elements().copied().collect();
+        assert_eq!(&elements[1].bytes[..], &cell.as_ref()[32..64]);
+        let mut elements: [Bytes32; FIELD_ELEMENTS_PER_CELL] = elements.try_into().unwrap();
+        assert_eq!(&Cell::from_field_elements(&elements).unwrap(), cell);
+
+        elements[7] = Bytes32::new([0xff; 32]);
+        assert!(matches!(
+            Cell::from_field_elements(&elements),
+            Err(Error::NonCanonicalFieldElement(7))
+        ));
+    }
+
+    #[test]
+    fn test_from_bytes_entry_points() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let z = Bytes32::new([1; 32]);
+
+        assert_eq!(
+            kzg_settings
+                .blob_to_kzg_commitment_from_bytes(blob.as_ref())
+                .unwrap(),
+            kzg_settings.blob_to_kzg_commitment(&blob).unwrap()
+        );
+        assert_eq!(
+            kzg_settings
+                .compute_kzg_proof_from_bytes(blob.as_ref(), z.as_ref())
+                .unwrap(),
+            kzg_settings.compute_kzg_proof(&blob, &z).unwrap()
+        );
+
+        let short_blob = &blob.as_ref()[1..];
+        assert!(matches!(
+            kzg_settings.blob_to_kzg_commitment_from_bytes(short_blob),
+            Err(Error::InvalidBlobLength { .. })
+        ));
+        assert!(matches!(
+            kzg_settings.compute_kzg_proof_from_bytes(blob.as_ref(), &[1; 31]),
+            Err(Error::InvalidBytesLength(_))
+        ));
+    }
+
+    #[test]
+    fn test_blobs_to_kzg_commitments() {
+        let mut rng = rand::thread_rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut blobs: Vec<Blob> = (0..4).map(|_| generate_random_blob(&mut rng)).collect();
+
+        let commitments = kzg_settings.blobs_to_kzg_commitments(&blobs).unwrap();
+        assert_eq!(commitments.len(), blobs.len());
+        for (blob, commitment) in blobs.iter().zip(&commitments) {
+            assert_eq!(
+                kzg_settings.blob_to_kzg_commitment(blob).unwrap(),
+                *commitment
+            );
+        }
+        assert!(kzg_settings
+            .blobs_to_kzg_commitments(&[])
+            .unwrap()
+            .is_empty());
+
+        blobs[1] = Blob::new([0xff; BYTES_PER_BLOB]);
+        blobs[3] = Blob::new([0xff; BYTES_PER_BLOB]);
+        let error = kzg_settings.blobs_to_kzg_commitments(&blobs).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InvalidBatchElement { index: 1, error } if matches!(*error, Error::InvalidArgument)
+        ));
+    }
+
+    #[test]
+    fn test_load_trusted_setup_from_reader() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let file_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let contents = fs::read_to_string(trusted_setup_file).unwrap();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let commitment = file_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let proof = file_settings
+            .compute_blob_kzg_proof(&blob, &commitment)
+            .unwrap();
+
+        for contents in [contents.clone(), contents.replace('\n', "\r\n")] {
+            let reader = std::io::Cursor::new(contents.into_bytes());
+            let settings = KZGSettings::load_trusted_setup_from_reader(reader, 0).unwrap();
+            assert_eq!(settings.blob_to_kzg_commitment(&blob).unwrap(), commitment);
+            assert_eq!(
+                settings.compute_blob_kzg_proof(&blob, &commitment).unwrap(),
+                proof
+            );
+        }
+
+        let truncated = std::io::Cursor::new(&contents.as_bytes()[..contents.len() / 2]);
+        assert!(matches!(
+            KZGSettings::load_trusted_setup_from_reader(truncated, 0),
+            Err(Error::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[test]
+    fn test_malformed_trusted_setup() {
+        let contents = std::fs::read_to_string("src/trusted_setup.txt").unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        let reason = |setup: &str| match KZGSettings::parse_kzg_trusted_setup(setup, 0) {
+            Err(Error::InvalidTrustedSetup(reason)) => reason,
+            result => panic!("unexpected result {:?}", result.map(|_| ())),
+        };
+
+        let truncated = lines[..100].join("\n");
+        assert!(reason(&truncated).contains("unexpected end of file"));
+
+        let mut bad_hex = lines.clone();
+        let bad_line = bad_hex[2].replacen('a', "g", 1);
+        bad_hex[2] = &bad_line;
+        assert!(reason(&bad_hex.join("\n")).contains("line 3: invalid g1 point"));
+
+        let mut wrong_count = lines.clone();
+        wrong_count[0] = "4095";
+        assert!(reason(&wrong_count.join("\n")).contains("line 1: expected 4096 g1 points"));
+
+        let mut extra_line = lines.clone();
+        extra_line.push(lines[2]);
+        assert!(reason(&extra_line.join("\n")).contains("unexpected line"));
+
+        // The file is parsed by the C library, which reports any malformed input the same way.
+        let truncated_file =
+            std::env::temp_dir().join(format!("c-kzg-truncated-setup-{}.txt", std::process::id()));
+        std::fs::write(&truncated_file, truncated).unwrap();
+        let result = KZGSettings::load_trusted_setup_file(&truncated_file, 0);
+        std::fs::remove_file(&truncated_file).unwrap();
+        assert!(matches!(result, Err(Error::InvalidTrustedSetup(_))));
+    }
+
+    #[test]
+    fn test_settings_from_bytes() {
+        const TRUSTED_SETUP: &[u8] = include_bytes!("../../../../src/trusted_setup.txt");
+        let file_settings =
+            KZGSettings::load_trusted_setup_file(Path::new("src/trusted_setup.txt"), 0).unwrap();
+        let settings = KZGSettings::from_bytes(TRUSTED_SETUP, 0).unwrap();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        assert_eq!(
+            settings.blob_to_kzg_commitment(&blob).unwrap(),
+            file_settings.blob_to_kzg_commitment(&blob).unwrap()
+        );
+
+        assert!(matches!(
+            KZGSettings::from_bytes(&[0xff, 0xfe], 0),
+            Err(Error::LoadingTrustedSetupFailed(KzgErrors::FileFormatError))
+        ));
+        assert!(KZGSettings::from_bytes(&TRUSTED_SETUP[..1000], 0).is_err());
+    }
+
+    #[test]
+    fn test_debug_truncated_hex() {
+        let mut bytes = [0u8; 48];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        assert_eq!(
+            format!("{:?}", KZGCommitment::from_bytes(&bytes).unwrap()),
+            "KzgCommitment(0x00010203...2c2d2e2f)"
+        );
+        assert_eq!(
+            format!("{:?}", KZGProof::from_bytes(&bytes).unwrap()),
+            "KzgProof(0x00010203...2c2d2e2f)"
+        );
+        assert_eq!(
+            format!("{:?}", Bytes32::from_bytes(&bytes[..32]).unwrap()),
+            "Bytes32(0x00010203...1c1d1e1f)"
+        );
+
+        let mut blob = [0u8; BYTES_PER_BLOB];
+        blob[..8].copy_from_slice(&bytes[..8]);
+        blob[BYTES_PER_BLOB - 8..].copy_from_slice(&bytes[40..]);
+        assert_eq!(
+            format!("{:?}", Blob::new(blob)),
+            "Blob(0x0001020304050607...28292a2b2c2d2e2f)"
+        );
+        assert_eq!(
+            format!("{:?}", Some(Bytes48::new(bytes))),
+            "Some(Bytes48(0x00010203...2c2d2e2f))"
+        );
+    }
+
+    #[test]
+    fn test_precompute() {
+        assert_eq!(Precompute::default(), Precompute::NONE);
+        assert_eq!(Precompute::from(0), Precompute::NONE);
+        assert_eq!(Precompute::level(0).unwrap(), Precompute::NONE);
+        assert_eq!(Precompute::from(8), Precompute::level(8).unwrap());
+        assert_eq!(Precompute::from_u64(15).unwrap().as_u64(), 15);
+        assert!(Precompute::from_u64(16).is_err());
+        assert!(Precompute::level(16).is_err());
+
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        for precompute in [Precompute::NONE, Precompute::level(2).unwrap()] {
+            let kzg_settings =
+                KZGSettings::load_trusted_setup_file(trusted_setup_file, precompute).unwrap();
+            assert_eq!(kzg_settings.precompute(), precompute);
+            assert_eq!(kzg_settings.clone().precompute(), precompute);
+        }
+        assert!(KZGSettings::load_trusted_setup_file(trusted_setup_file, 16).is_err());
+        assert!(KZGSettings::load_trusted_setup_file(trusted_setup_file, u64::MAX).is_err());
+        assert_eq!(Precompute::from(1000).as_u64(), 1000);
+        let error = KZGSettings::load_trusted_setup_file(trusted_setup_file, 1000).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid precompute value 1000, must be at most 15"
+        );
+    }
+
+    #[test]
+    fn test_clone_settings() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let blob = generate_random_blob(&mut rand::thread_rng());
+
+        for precompute in [0, 2] {
+            let kzg_settings =
+                KZGSettings::load_trusted_setup_file(trusted_setup_file, precompute).unwrap();
+            let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+            let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+
+            let cloned = kzg_settings.clone();
+            drop(kzg_settings);
+            assert_eq!(cloned.blob_to_kzg_commitment(&blob).unwrap(), commitment);
+            assert_eq!(
+                cloned.compute_cells_and_kzg_proofs(&blob).unwrap(),
+                (cells, proofs)
+            );
+            let cloned_again = cloned.clone();
+            drop(cloned);
+            assert_eq!(
+                cloned_again.blob_to_kzg_commitment(&blob).unwrap(),
+                commitment
+            );
+        }
+    }
+
+    #[test]
+    fn test_setup_points() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        assert_eq!(kzg_settings.g1_points().len(), FIELD_ELEMENTS_PER_BLOB);
+        assert_eq!(
+            kzg_settings.g1_points_lagrange_brp().len(),
+            FIELD_ELEMENTS_PER_BLOB
+        );
+        assert_eq!(kzg_settings.g2_points().len(), NUM_G2_POINTS);
+        assert_eq!(kzg_settings.num_g1_points(), FIELD_ELEMENTS_PER_BLOB);
+        assert_eq!(kzg_settings.num_g2_points(), 65);
+
+        // The first point of each monomial setup is the generator.
+        unsafe {
+            assert!(blst::blst_p1_is_equal(
+                &kzg_settings.g1_points()[0],
+                blst::blst_p1_generator()
+            ));
+            assert!(blst::blst_p2_is_equal(
+                &kzg_settings.g2_points()[0],
+                blst::blst_p2_generator()
+            ));
+        }
+
+        // The sum of the Lagrange points is the generator, which is the commitment to the
+        // constant one polynomial.
+        let mut sum = blst::blst_p1::default();
+        for point in kzg_settings.g1_points_lagrange_brp() {
+            unsafe { blst::blst_p1_add_or_double(&mut sum, &sum, point) };
+        }
+        unsafe { assert!(blst::blst_p1_is_equal(&sum, blst::blst_p1_generator())) };
+    }
 
-#[cfg(test)]
-#[allow(unused_imports, dead_code)]
-mod tests {
-    use super::*;
-    use rand::{rngs::ThreadRng, Rng};
-    use std::{fs, path::PathBuf};
-    use test_formats::{
-        blob_to_kzg_commitment_test, compute_blob_kzg_proof, compute_cells_and_kzg_proofs,
-        compute_kzg_proof, recover_cells_and_kzg_proofs, verify_blob_kzg_proof,
-        verify_blob_kzg_proof_batch, verify_cell_kzg_proof_batch, verify_kzg_proof,
-    };
+    #[test]
+    fn test_roots_of_unity() {
+        assert_eq!(
+            bls_modulus().to_hex(),
+            "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+        );
 
-    fn generate_random_blob(rng: &mut ThreadRng) -> Blob {
-        let mut arr = [0u8; BYTES_PER_BLOB];
-        rng.fill(&mut arr[..]);
-        // Ensure that the blob is canonical by ensuring that
-        // each field element contained in the blob is < BLS_MODULUS
-        for i in 0..FIELD_ELEMENTS_PER_BLOB {
-            arr[i * BYTES_PER_FIELD_ELEMENT] = 0;
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let one = Bytes32::new(one);
+        let mut minus_one = bls_modulus();
+        minus_one.bytes[31] -= 1;
+
+        let roots: Vec<Bytes32> = kzg_settings.roots_of_unity().collect();
+        assert_eq!(roots.len(), FIELD_ELEMENTS_PER_EXT_BLOB + 1);
+        assert_eq!(roots[0], one);
+        assert_eq!(roots[FIELD_ELEMENTS_PER_EXT_BLOB / 2], minus_one);
+        assert_eq!(roots[FIELD_ELEMENTS_PER_EXT_BLOB], one);
+
+        let brp_roots: Vec<Bytes32> = kzg_settings.brp_roots_of_unity().collect();
+        assert_eq!(brp_roots.len(), FIELD_ELEMENTS_PER_EXT_BLOB);
+        assert_eq!(brp_roots[0], one);
+        assert_eq!(brp_roots[1], minus_one);
+
+        // Evaluating a blob at one of its evaluation points returns the field element there.
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        for i in [0, 1, 1234, FIELD_ELEMENTS_PER_BLOB - 1] {
+            let proof = kzg_settings
+                .compute_kzg_proof(&blob, &brp_roots[i])
+                .unwrap();
+            assert_eq!(
+                &proof.y.bytes[..],
+                &blob.bytes[i * BYTES_PER_FIELD_ELEMENT..(i + 1) * BYTES_PER_FIELD_ELEMENT]
+            );
         }
-        arr.into()
     }
 
-    fn test_simple(trusted_setup_file: &Path) {
-        let mut rng = rand::thread_rng();
-        assert!(trusted_setup_file.exists());
+    #[test]
+    fn test_evaluate_blob_at() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut rng = rand::thread_rng();
+        let blob = generate_random_blob(&mut rng);
+
+        let mut z = [0u8; BYTES_PER_FIELD_ELEMENT];
+        rng.fill(&mut z[..]);
+        z[0] = 0;
+        let z = Bytes32::new(z);
+        assert_eq!(
+            kzg_settings.evaluate_blob_at(&blob, &z).unwrap(),
+            kzg_settings.compute_kzg_proof(&blob, &z).unwrap().y
+        );
+
+        let roots: Vec<Bytes32> = kzg_settings.brp_roots_of_unity().collect();
+        for i in [0, 42, FIELD_ELEMENTS_PER_BLOB - 1] {
+            assert_eq!(
+                &kzg_settings
+                    .evaluate_blob_at(&blob, &roots[i])
+                    .unwrap()
+                    .bytes[..],
+                &blob.bytes[i * BYTES_PER_FIELD_ELEMENT..(i + 1) * BYTES_PER_FIELD_ELEMENT]
+            );
+        }
 
-        let num_blobs: usize = rng.gen_range(1..16);
-        let mut blobs: Vec<Blob> = (0..num_blobs)
-            .map(|_| generate_random_blob(&mut rng))
-            .collect();
+        assert!(matches!(
+            kzg_settings.evaluate_blob_at(&blob, &bls_modulus()),
+            Err(Error::InvalidArgument)
+        ));
+    }
 
-        let commitments: Vec<Bytes48> = blobs
-            .iter()
-            .map(|blob| kzg_settings.blob_to_kzg_commitment(blob).unwrap())
-            .map(|commitment| commitment.to_bytes())
-            .collect();
+    #[test]
+    fn test_shared_settings() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<KZGSettings>();
+        assert_send_sync::<Arc<KZGSettings>>();
 
-        let proofs: Vec<Bytes48> = blobs
-            .iter()
-            .zip(commitments.iter())
-            .map(|(blob, commitment)| {
-                kzg_settings
-                    .compute_blob_kzg_proof(blob, commitment)
-                    .unwrap()
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0)
+            .unwrap()
+            .into_shared();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let kzg_settings = SharedKzgSettings::clone(&kzg_settings);
+                let blob = blob.clone();
+                std::thread::spawn(move || kzg_settings.blob_to_kzg_commitment(&blob).unwrap())
             })
-            .map(|proof| proof.to_bytes())
             .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), commitment);
+        }
+    }
 
-        assert!(kzg_settings
-            .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
-            .unwrap());
+    #[test]
+    fn test_error_display() {
+        assert_eq!(C_KZG_RET::C_KZG_BADARGS.to_string(), "invalid argument");
+        assert_eq!(
+            C_KZG_RET::C_KZG_MALLOC.to_string(),
+            "could not allocate memory"
+        );
+        assert!(matches!(
+            Error::from(C_KZG_RET::C_KZG_BADARGS),
+            Error::InvalidArgument
+        ));
+        assert!(matches!(
+            Error::from(C_KZG_RET::C_KZG_MALLOC),
+            Error::AllocationFailed
+        ));
+        assert_eq!(
+            Error::from(C_KZG_RET::C_KZG_ERROR).to_string(),
+            "Internal c-kzg error: internal error"
+        );
+
+        for ret in [
+            C_KZG_RET::C_KZG_BADARGS,
+            C_KZG_RET::C_KZG_ERROR,
+            C_KZG_RET::C_KZG_MALLOC,
+        ] {
+            assert_eq!(Error::from(ret).c_kzg_ret(), Some(ret));
+        }
+        assert_eq!(Error::InvalidCellIndex(128).c_kzg_ret(), None);
 
-        blobs.pop();
+        let error: Box<dyn std::error::Error> = Box::new(Error::from(C_KZG_RET::C_KZG_ERROR));
+        assert_eq!(error.source().unwrap().to_string(), "internal error");
 
+        let mut rng = rand::thread_rng();
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let non_canonical_blob = Blob::new([0xff; BYTES_PER_BLOB]);
         let error = kzg_settings
-            .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
+            .blob_to_kzg_commitment(&non_canonical_blob)
             .unwrap_err();
-        assert!(matches!(error, Error::MismatchLength(_)));
+        assert!(matches!(error, Error::InvalidArgument));
+        let blob = generate_random_blob(&mut rng);
+        let error = kzg_settings
+            .compute_blob_kzg_proof(&blob, &KZGCommitment::from([0xff; 48]))
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidArgument));
+    }
 
-        let incorrect_blob = generate_random_blob(&mut rng);
-        blobs.push(incorrect_blob);
+    #[test]
+    fn test_bytes48_conversions() {
+        let bytes = [1u8; 48];
+        let commitment = KZGCommitment::from(bytes);
+        let commitment_bytes: Bytes48 = commitment.into();
+        assert_eq!(commitment_bytes, Bytes48::from(bytes));
+        assert_eq!(KZGCommitment::from(commitment_bytes), commitment);
+        assert_eq!(<[u8; 48]>::from(commitment), bytes);
+
+        let proof = KZGProof::from(bytes);
+        let proof_bytes: Bytes48 = proof.into();
+        assert_eq!(proof_bytes, Bytes48::from(bytes));
+        assert_eq!(KZGProof::from(proof_bytes), proof);
+        assert_eq!(<[u8; 48]>::from(proof), bytes);
+    }
 
-        assert!(!kzg_settings
-            .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
-            .unwrap());
+    #[test]
+    fn test_hex_conversions() {
+        let blob = Blob::new([0xab; BYTES_PER_BLOB]);
+        assert_eq!(Blob::from_hex(&blob.to_hex()).unwrap(), blob);
+        let bytes32 = Bytes32::new([0x12; 32]);
+        assert_eq!(bytes32.to_hex(), format!("0x{}", "12".repeat(32)));
+        assert_eq!(Bytes32::from_hex(&bytes32.to_hex()).unwrap(), bytes32);
+        assert_eq!(Bytes32::from_hex(&"12".repeat(32)).unwrap(), bytes32);
+        let bytes48 = Bytes48::new([0xcd; 48]);
+        assert_eq!(Bytes48::from_hex(&bytes48.to_hex()).unwrap(), bytes48);
+
+        let error = Bytes32::from_hex("0x123").unwrap_err();
+        assert!(matches!(error, Error::InvalidHexFormat(_)));
+        let error = Bytes48::from_hex(&format!("0x{}zz", "00".repeat(47))).unwrap_err();
+        assert!(matches!(error, Error::InvalidHexFormat(_)));
+        let error = Blob::from_hex("0x00").unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InvalidBlobLength {
+                expected: BYTES_PER_BLOB,
+                got: 1
+            }
+        ));
     }
 
     #[test]
-    fn test_end_to_end() {
-        let trusted_setup_file = Path::new("src/trusted_setup.txt");
-        test_simple(trusted_setup_file);
+    fn test_from_str_and_display() {
+        let commitment_hex = format!("0x{}", "a0".repeat(BYTES_PER_COMMITMENT));
+        let commitment: KZGCommitment = commitment_hex.parse().unwrap();
+        assert_eq!(commitment.to_string(), commitment_hex);
+        assert_eq!(
+            "A0".repeat(BYTES_PER_COMMITMENT)
+                .parse::<KZGCommitment>()
+                .unwrap(),
+            commitment
+        );
+        let proof: KZGProof = commitment_hex.parse().unwrap();
+        assert_eq!(proof.to_string(), commitment_hex);
+
+        let blob = Blob::new([0xab; BYTES_PER_BLOB]);
+        assert_eq!(blob.to_string().parse::<Blob>().unwrap(), blob);
+        let cell = Cell::new([0xcd; BYTES_PER_CELL]);
+        assert_eq!(cell.to_string().parse::<Cell>().unwrap(), cell);
+        let bytes32 = Bytes32::new([0x12; 32]);
+        assert_eq!(bytes32.to_string().parse::<Bytes32>().unwrap(), bytes32);
+        let bytes48 = Bytes48::new([0x34; 48]);
+        assert_eq!(bytes48.to_string().parse::<Bytes48>().unwrap(), bytes48);
+
+        let error = "0x1234".parse::<KZGCommitment>().unwrap_err();
+        assert!(matches!(error, Error::InvalidKzgCommitment(_)));
+        let error = "0xzz".parse::<KZGProof>().unwrap_err();
+        assert!(matches!(error, Error::InvalidHexFormat(_)));
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn test_constant_time_eq() {
+        use subtle::ConstantTimeEq;
+
+        let a = Bytes48::from([1; 48]);
+        let b = Bytes48::from([2; 48]);
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+        let a = Bytes32::from([1; 32]);
+        let b = Bytes32::from([2; 32]);
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+        let a = KZGCommitment::from([1; 48]);
+        let b = KZGCommitment::from([2; 48]);
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+        let a = KZGProof::from([1; 48]);
+        let b = KZGProof::from([2; 48]);
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+
+        // `ct_eq` agrees with `==`, including when only the last byte differs.
+        let mut last_byte_differs = [1; 48];
+        last_byte_differs[47] = 2;
+        let values = [[1; 48], [2; 48], last_byte_differs];
+        for x in values {
+            for y in values {
+                let (a, b) = (Bytes48::from(x), Bytes48::from(y));
+                assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+                let (a, b) = (KZGCommitment::from(x), KZGCommitment::from(y));
+                assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+                let (a, b) = (KZGProof::from(x), KZGProof::from(y));
+                assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+                let (a, b) = (
+                    Bytes32::from_bytes(&x[16..]).unwrap(),
+                    Bytes32::from_bytes(&y[16..]).unwrap(),
+                );
+                assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+            }
+        }
+    }
+
+    /// Returns the reference test files matching `pattern`.
+    ///
+    /// The files are read from the `tests` directory of this repository, or from the directory in
+    /// the `KZG_SPEC_TESTS` environment variable if it is set, e.g. a `tests/general/<fork>/kzg`
+    /// directory of a consensus-spec-tests release. Test formats that are missing from that
+    /// directory are skipped.
+    fn spec_test_files(pattern: &str) -> Vec<PathBuf> {
+        let custom_root = std::env::var_os("KZG_SPEC_TESTS").map(PathBuf::from);
+        let root = custom_root
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("tests"));
+        let pattern = root.join(pattern);
+        let test_files: Vec<PathBuf> = glob::glob(pattern.to_str().unwrap())
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        if custom_root.is_none() {
+            assert!(!test_files.is_empty());
+        }
+        test_files
     }
 
-    const BLOB_TO_KZG_COMMITMENT_TESTS: &str = "tests/blob_to_kzg_commitment/*/*/*";
-    const COMPUTE_KZG_PROOF_TESTS: &str = "tests/compute_kzg_proof/*/*/*";
-    const COMPUTE_BLOB_KZG_PROOF_TESTS: &str = "tests/compute_blob_kzg_proof/*/*/*";
-    const VERIFY_KZG_PROOF_TESTS: &str = "tests/verify_kzg_proof/*/*/*";
-    const VERIFY_BLOB_KZG_PROOF_TESTS: &str = "tests/verify_blob_kzg_proof/*/*/*";
-    const VERIFY_BLOB_KZG_PROOF_BATCH_TESTS: &str = "tests/verify_blob_kzg_proof_batch/*/*/*";
+    const BLOB_TO_KZG_COMMITMENT_TESTS: &str = "blob_to_kzg_commitment/*/*/*";
+    const COMPUTE_KZG_PROOF_TESTS: &str = "compute_kzg_proof/*/*/*";
+    const COMPUTE_BLOB_KZG_PROOF_TESTS: &str = "compute_blob_kzg_proof/*/*/*";
+    const VERIFY_KZG_PROOF_TESTS: &str = "verify_kzg_proof/*/*/*";
+    const VERIFY_BLOB_KZG_PROOF_TESTS: &str = "verify_blob_kzg_proof/*/*/*";
+    const VERIFY_BLOB_KZG_PROOF_BATCH_TESTS: &str = "verify_blob_kzg_proof_batch/*/*/*";
 
-    const COMPUTE_CELLS_AND_KZG_PROOFS_TESTS: &str = "tests/compute_cells_and_kzg_proofs/*/*/*";
-    const RECOVER_CELLS_AND_KZG_PROOFS_TESTS: &str = "tests/recover_cells_and_kzg_proofs/*/*/*";
-    const VERIFY_CELL_KZG_PROOF_BATCH_TESTS: &str = "tests/verify_cell_kzg_proof_batch/*/*/*";
+    const COMPUTE_CELLS_AND_KZG_PROOFS_TESTS: &str = "compute_cells_and_kzg_proofs/*/*/*";
+    const RECOVER_CELLS_AND_KZG_PROOFS_TESTS: &str = "recover_cells_and_kzg_proofs/*/*/*";
+    const VERIFY_CELL_KZG_PROOF_BATCH_TESTS: &str = "verify_cell_kzg_proof_batch/*/*/*";
 
     #[test]
     fn test_blob_to_kzg_commitment() {
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(BLOB_TO_KZG_COMMITMENT_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
+        let test_files = spec_test_files(BLOB_TO_KZG_COMMITMENT_TESTS);
 
         #[allow(unused_variables)]
         for (index, test_file) in test_files.iter().enumerate() {
@@ -1035,11 +3609,7 @@ mod tests {
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(COMPUTE_KZG_PROOF_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
+        let test_files = spec_test_files(COMPUTE_KZG_PROOF_TESTS);
 
         #[allow(unused_variables)]
         for (index, test_file) in test_files.iter().enumerate() {
@@ -1066,7 +3636,7 @@ mod tests {
             }
 
             match kzg_settings.compute_kzg_proof(&blob, &z) {
-                Ok((proof, y)) => {
+                Ok(KzgProofWithEval { proof, y }) => {
                     assert_eq!(proof.bytes, test.get_output().unwrap().0.bytes);
                     assert_eq!(y.bytes, test.get_output().unwrap().1.bytes);
                 }
@@ -1080,11 +3650,7 @@ mod tests {
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(COMPUTE_BLOB_KZG_PROOF_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
+        let test_files = spec_test_files(COMPUTE_BLOB_KZG_PROOF_TESTS);
 
         #[allow(unused_variables)]
         for (index, test_file) in test_files.iter().enumerate() {
@@ -1123,11 +3689,7 @@ mod tests {
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(VERIFY_KZG_PROOF_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
+        let test_files = spec_test_files(VERIFY_KZG_PROOF_TESTS);
 
         #[allow(unused_variables)]
         for (index, test_file) in test_files.iter().enumerate() {
@@ -1161,7 +3723,7 @@ mod tests {
             }
 
             match kzg_settings.verify_kzg_proof(&commitment, &z, &y, &proof) {
-                Ok(res) => assert_eq!(res, test.get_output().unwrap()),
+                Ok(res) => assert_eq!(res.is_valid(), test.get_output().unwrap()),
                 _ => assert!(test.get_output().is_none()),
             }
         }
@@ -1172,11 +3734,7 @@ mod tests {
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(VERIFY_BLOB_KZG_PROOF_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
+        let test_files = spec_test_files(VERIFY_BLOB_KZG_PROOF_TESTS);
 
         #[allow(unused_variables)]
         for (index, test_file) in test_files.iter().enumerate() {
@@ -1208,22 +3766,208 @@ mod tests {
             }
 
             match kzg_settings.verify_blob_kzg_proof(&blob, &commitment, &proof) {
-                Ok(res) => assert_eq!(res, test.get_output().unwrap()),
+                Ok(res) => assert_eq!(res.is_valid(), test.get_output().unwrap()),
                 _ => assert!(test.get_output().is_none()),
             }
         }
     }
 
+    #[test]
+    fn test_batch_length_mismatch() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blobs = [
+            Blob::new([0; BYTES_PER_BLOB]),
+            Blob::new([0; BYTES_PER_BLOB]),
+        ];
+        let commitments = [KZGCommitment::default(); 2];
+        let proofs = [KZGProof::default(); 2];
+        let error = kzg_settings
+            .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs[1..])
+            .unwrap_err();
+        assert_eq!(error.to_string(), "There are 2 blobs and 1 proofs");
+
+        let cell_indices = [CellIndex::default(); 2];
+        let cells = [Cell::default(); 2];
+        let error = kzg_settings
+            .verify_cell_kzg_proof_batch(&commitments[1..], &cell_indices, &cells, &proofs)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "There are 2 cells and 1 commitments");
+        let error = kzg_settings
+            .verify_cell_kzg_proof_batch(&commitments, &cell_indices[1..], &cells, &proofs)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "There are 2 cells and 1 cell indices");
+        let error = kzg_settings
+            .verify_cell_kzg_proof_batch(&commitments, &cell_indices, &cells, &proofs[1..])
+            .unwrap_err();
+        assert_eq!(error.to_string(), "There are 2 cells and 1 proofs");
+
+        let error = kzg_settings
+            .recover_cells_and_kzg_proofs(&cell_indices[1..], &cells)
+            .unwrap_err();
+        assert!(matches!(error, Error::MismatchLength(_)));
+        assert_eq!(error.to_string(), "There are 1 cell indices and 2 cells");
+    }
+
+    #[test]
+    fn test_verify_invalid_and_malformed_proofs() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut rng = rand::thread_rng();
+        let blob = generate_random_blob(&mut rng);
+        let other_blob = generate_random_blob(&mut rng);
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let other_proof = kzg_settings
+            .compute_blob_kzg_proof(
+                &other_blob,
+                &kzg_settings.blob_to_kzg_commitment(&other_blob).unwrap(),
+            )
+            .unwrap();
+        let z = Bytes32::new([0; 32]);
+        let y = kzg_settings.compute_kzg_proof(&blob, &z).unwrap().y;
+
+        // A valid point which is the wrong proof does not verify.
+        assert!(!kzg_settings
+            .verify_blob_kzg_proof(&blob, &commitment, &other_proof)
+            .unwrap()
+            .is_valid());
+        assert!(!kzg_settings
+            .verify_kzg_proof(&commitment, &z, &y, &other_proof)
+            .unwrap()
+            .is_valid());
+
+        // Bytes which are not a point are an error.
+        let garbage = KZGProof::from(Bytes48::new([0xff; 48]));
+        assert!(matches!(
+            kzg_settings.verify_blob_kzg_proof(&blob, &commitment, &garbage),
+            Err(Error::InvalidArgument)
+        ));
+        assert!(matches!(
+            kzg_settings.verify_kzg_proof(&commitment, &z, &y, &garbage),
+            Err(Error::InvalidArgument)
+        ));
+        assert!(matches!(
+            kzg_settings.verify_kzg_proof(&commitment, &z, &bls_modulus(), &other_proof),
+            Err(Error::InvalidArgument)
+        ));
+    }
+
+    #[test]
+    fn test_verify_kzg_proof_batch() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut rng = rand::thread_rng();
+        let mut commitments = Vec::new();
+        let mut zs = Vec::new();
+        let mut ys = Vec::new();
+        let mut proofs = Vec::new();
+        for _ in 0..4 {
+            let blob = generate_random_blob(&mut rng);
+            let z = *generate_random_blob(&mut rng)
+                .field_elements()
+                .next()
+                .unwrap();
+            let KzgProofWithEval { proof, y } = kzg_settings.compute_kzg_proof(&blob, &z).unwrap();
+            commitments.push(kzg_settings.blob_to_kzg_commitment(&blob).unwrap());
+            zs.push(z);
+            ys.push(y);
+            proofs.push(proof);
+        }
+
+        for n in 0..=4 {
+            assert!(kzg_settings
+                .verify_kzg_proof_batch(&commitments[..n], &zs[..n], &ys[..n], &proofs[..n])
+                .unwrap()
+                .is_valid());
+        }
+        let mut wrong_ys = ys.clone();
+        wrong_ys[2].bytes[31] ^= 1;
+        assert!(!kzg_settings
+            .verify_kzg_proof_batch(&commitments, &zs, &wrong_ys, &proofs)
+            .unwrap()
+            .is_valid());
+
+        let error = kzg_settings
+            .verify_kzg_proof_batch(&commitments, &zs, &ys[1..], &proofs)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "There are 4 commitments and 3 ys");
+    }
+
+    #[test]
+    fn test_verify_methods() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut rng = rand::thread_rng();
+        let blob = generate_random_blob(&mut rng);
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let proof = kzg_settings
+            .compute_blob_kzg_proof(&blob, &commitment)
+            .unwrap();
+        assert!(proof
+            .verify(&blob, &commitment, &kzg_settings)
+            .unwrap()
+            .is_valid());
+        assert!(commitment
+            .verify_blob(&blob, &proof, &kzg_settings)
+            .unwrap()
+            .is_valid());
+
+        let other_blob = generate_random_blob(&mut rng);
+        assert!(!proof
+            .verify(&other_blob, &commitment, &kzg_settings)
+            .unwrap()
+            .is_valid());
+        assert!(!commitment
+            .verify_blob(&other_blob, &proof, &kzg_settings)
+            .unwrap()
+            .is_valid());
+    }
+
+    #[test]
+    fn test_verify_blob_kzg_proof_unchecked() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut rng = rand::thread_rng();
+        let blob = generate_random_blob(&mut rng);
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let proof = kzg_settings
+            .compute_blob_kzg_proof(&blob, &commitment)
+            .unwrap();
+        assert!(kzg_settings
+            .verify_blob_kzg_proof_unchecked(&blob, &commitment, &proof)
+            .unwrap()
+            .is_valid());
+        let other_blob = generate_random_blob(&mut rng);
+        assert!(!kzg_settings
+            .verify_blob_kzg_proof_unchecked(&other_blob, &commitment, &proof)
+            .unwrap()
+            .is_valid());
+
+        // A point on the curve outside of G1 is only rejected by the checked path.
+        let not_in_g1 = KZGProof::from_hex(
+            "0x8123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+        )
+        .unwrap();
+        assert!(matches!(
+            kzg_settings.verify_blob_kzg_proof(&blob, &commitment, &not_in_g1),
+            Err(Error::InvalidArgument)
+        ));
+        assert!(kzg_settings
+            .verify_blob_kzg_proof_unchecked(&blob, &commitment, &not_in_g1)
+            .is_ok());
+        // Bytes which are not a point on the curve are still rejected.
+        let not_on_curve = KZGProof::from(Bytes48::new([0xff; 48]));
+        assert!(kzg_settings
+            .verify_blob_kzg_proof_unchecked(&blob, &commitment, &not_on_curve)
+            .is_err());
+    }
+
     #[test]
     fn test_verify_blob_kzg_proof_batch() {
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(VERIFY_BLOB_KZG_PROOF_BATCH_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
+        let test_files = spec_test_files(VERIFY_BLOB_KZG_PROOF_BATCH_TESTS);
 
         #[allow(unused_variables)]
         for (index, test_file) in test_files.iter().enumerate() {
@@ -1261,7 +4005,7 @@ mod tests {
             }
 
             match kzg_settings.verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs) {
-                Ok(res) => assert_eq!(res, test.get_output().unwrap()),
+                Ok(res) => assert_eq!(res.is_valid(), test.get_output().unwrap()),
                 _ => assert!(test.get_output().is_none()),
             }
         }
@@ -1272,11 +4016,7 @@ mod tests {
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(COMPUTE_CELLS_AND_KZG_PROOFS_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
+        let test_files = spec_test_files(COMPUTE_CELLS_AND_KZG_PROOFS_TESTS);
 
         #[allow(unused_variables)]
         for (index, test_file) in test_files.iter().enumerate() {
@@ -1306,25 +4046,41 @@ mod tests {
                 Ok((cells, proofs)) => {
                     let (expected_cells, expected_proofs) = test.get_output().unwrap();
                     assert_eq!(cells.as_slice(), expected_cells);
+                    assert_eq!(kzg_settings.compute_cells(&blob).unwrap(), cells);
                     let proofs_as_bytes: Vec<Bytes48> =
                         proofs.iter().map(|p| p.to_bytes()).collect();
                     assert_eq!(proofs_as_bytes, expected_proofs);
                 }
-                _ => assert!(test.get_output().is_none()),
+                _ => {
+                    assert!(test.get_output().is_none());
+                    assert!(kzg_settings.compute_cells(&blob).is_err());
+                }
             }
         }
     }
 
+    #[test]
+    fn test_compute_cell_and_kzg_proof() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+        for index in [0, 1, 63, 64, CELLS_PER_EXT_BLOB as u64 - 1] {
+            let cell_index = CellIndex::new(index).unwrap();
+            let (cell, proof) = kzg_settings
+                .compute_cell_and_kzg_proof(&blob, cell_index)
+                .unwrap();
+            assert_eq!(cell, cells[index as usize]);
+            assert_eq!(proof, proofs[index as usize]);
+        }
+    }
+
     #[test]
     fn test_recover_cells_and_kzg_proofs() {
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(RECOVER_CELLS_AND_KZG_PROOFS_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
+        let test_files = spec_test_files(RECOVER_CELLS_AND_KZG_PROOFS_TESTS);
 
         #[allow(unused_variables)]
         for (index, test_file) in test_files.iter().enumerate() {
@@ -1357,7 +4113,9 @@ mod tests {
                 }
             }
 
-            match kzg_settings.recover_cells_and_kzg_proofs(&cell_indices, &cells) {
+            match CellIndex::from_slice(&cell_indices).and_then(|cell_indices| {
+                kzg_settings.recover_cells_and_kzg_proofs(cell_indices, &cells)
+            }) {
                 Ok((recovered_cells, recovered_proofs)) => {
                     let (expected_cells, expected_proofs) = test.get_output().unwrap();
                     assert_eq!(recovered_cells.as_slice(), expected_cells);
@@ -1375,11 +4133,7 @@ mod tests {
         let trusted_setup_file = Path::new("src/trusted_setup.txt");
         assert!(trusted_setup_file.exists());
         let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
-        let test_files: Vec<PathBuf> = glob::glob(VERIFY_CELL_KZG_PROOF_BATCH_TESTS)
-            .unwrap()
-            .map(Result::unwrap)
-            .collect();
-        assert!(!test_files.is_empty());
+        let test_files = spec_test_files(VERIFY_CELL_KZG_PROOF_BATCH_TESTS);
 
         #[allow(unused_variables)]
         for (index, test_file) in test_files.iter().enumerate() {
@@ -1420,15 +4174,253 @@ mod tests {
                 }
             }
 
-            match kzg_settings.verify_cell_kzg_proof_batch(
+            match CellIndex::from_slice(&cell_indices).and_then(|cell_indices| {
+                kzg_settings.verify_cell_kzg_proof_batch(
+                    &commitments,
+                    cell_indices,
+                    &cells,
+                    &proofs,
+                )
+            }) {
+                Ok(res) => assert_eq!(res.is_valid(), test.get_output().unwrap()),
+                _ => assert!(test.get_output().is_none()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_cell_kzg_proof_batch_grouped() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let mut rng = rand::thread_rng();
+        let blobs = [
+            generate_random_blob(&mut rng),
+            generate_random_blob(&mut rng),
+        ];
+        let commitments = [
+            kzg_settings.blob_to_kzg_commitment(&blobs[0]).unwrap(),
+            kzg_settings.blob_to_kzg_commitment(&blobs[1]).unwrap(),
+        ];
+        let (cells0, proofs0) = kzg_settings
+            .compute_cells_and_kzg_proofs(&blobs[0])
+            .unwrap();
+        let (cells1, proofs1) = kzg_settings
+            .compute_cells_and_kzg_proofs(&blobs[1])
+            .unwrap();
+
+        // Three cells share the first commitment and one uses the second.
+        let commitment_indices = [0u64, 0, 0, 1];
+        let cell_indices: Vec<CellIndex> = [3, 7, 100, 7]
+            .into_iter()
+            .map(|index| CellIndex::new(index).unwrap())
+            .collect();
+        let cells = [cells0[3], cells0[7], cells0[100], cells1[7]];
+        let mut proofs = [proofs0[3], proofs0[7], proofs0[100], proofs1[7]];
+        let flat_commitments = commitment_indices.map(|index| commitments[index as usize]);
+
+        assert!(kzg_settings
+            .verify_cell_kzg_proof_batch_grouped(
+                &commitments,
+                &commitment_indices,
+                &cell_indices,
+                &cells,
+                &proofs
+            )
+            .unwrap()
+            .is_valid());
+        proofs.swap(0, 1);
+        let grouped = kzg_settings
+            .verify_cell_kzg_proof_batch_grouped(
                 &commitments,
+                &commitment_indices,
                 &cell_indices,
                 &cells,
                 &proofs,
-            ) {
-                Ok(res) => assert_eq!(res, test.get_output().unwrap()),
-                _ => assert!(test.get_output().is_none()),
-            }
+            )
+            .unwrap();
+        let flat = kzg_settings
+            .verify_cell_kzg_proof_batch(&flat_commitments, &cell_indices, &cells, &proofs)
+            .unwrap();
+        assert!(!grouped.is_valid());
+        assert_eq!(grouped, flat);
+
+        assert!(matches!(
+            kzg_settings.verify_cell_kzg_proof_batch_grouped(
+                &commitments,
+                &[0, 0, 2, 1],
+                &cell_indices,
+                &cells,
+                &proofs
+            ),
+            Err(Error::InvalidBatchElement { index: 2, .. })
+        ));
+        assert!(matches!(
+            kzg_settings.verify_cell_kzg_proof_batch_grouped(
+                &commitments,
+                &[0, 0, 0, u64::MAX],
+                &cell_indices,
+                &cells,
+                &proofs
+            ),
+            Err(Error::InvalidBatchElement { index: 3, .. })
+        ));
+        let error = kzg_settings
+            .verify_cell_kzg_proof_batch_grouped(
+                &commitments,
+                &commitment_indices[1..],
+                &cell_indices,
+                &cells,
+                &proofs,
+            )
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "There are 4 cells and 3 commitment indices"
+        );
+    }
+
+    #[test]
+    fn test_verify_cell_kzg_proof() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+
+        for i in [0, 1, CELLS_PER_EXT_BLOB - 1] {
+            let cell = &cells[i];
+            let proof = proofs[i];
+            let cell_index = CellIndex::new(i as u64).unwrap();
+            assert!(kzg_settings
+                .verify_cell_kzg_proof(&commitment, cell_index, cell, &proof)
+                .unwrap()
+                .is_valid());
+
+            // A valid proof for another cell must be rejected, just like the batch function.
+            let wrong_index = CellIndex::new((i as u64 + 1) % CELLS_PER_EXT_BLOB as u64).unwrap();
+            let single = kzg_settings
+                .verify_cell_kzg_proof(&commitment, wrong_index, cell, &proof)
+                .unwrap();
+            let batch = kzg_settings
+                .verify_cell_kzg_proof_batch(&[commitment], &[wrong_index], &[*cell], &[proof])
+                .unwrap();
+            assert!(!single.is_valid());
+            assert_eq!(single, batch);
         }
+
+        let error = CellIndex::new(CELLS_PER_EXT_BLOB as u64).unwrap_err();
+        assert!(matches!(error, Error::InvalidCellIndex(128)));
+    }
+
+    #[test]
+    fn test_recover_cells_and_kzg_proofs_from_half() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+
+        // Keep every other cell, which is exactly the minimum needed.
+        let cell_indices: Vec<CellIndex> = (0..CELLS_PER_EXT_BLOB as u64)
+            .step_by(2)
+            .map(|i| CellIndex::new(i).unwrap())
+            .collect();
+        let some_cells: Vec<Cell> = cell_indices
+            .iter()
+            .map(|&i| cells[i.as_u64() as usize])
+            .collect();
+        let (recovered_cells, recovered_proofs) = kzg_settings
+            .recover_cells_and_kzg_proofs(&cell_indices, &some_cells)
+            .unwrap();
+        assert_eq!(recovered_cells, cells);
+        assert_eq!(recovered_proofs, proofs);
+        assert_eq!(
+            kzg_settings
+                .recover_cells(&cell_indices, &some_cells)
+                .unwrap(),
+            recovered_cells
+        );
+
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let (verified_cells, verified_proofs) = kzg_settings
+            .recover_and_verify(&commitment, &cell_indices, &some_cells)
+            .unwrap();
+        assert_eq!(verified_cells, cells);
+        assert_eq!(verified_proofs, proofs);
+
+        let mut tampered_cells = some_cells.clone();
+        tampered_cells[3].as_mut()[BYTES_PER_CELL - 1] ^= 1;
+        assert!(matches!(
+            kzg_settings.recover_and_verify(&commitment, &cell_indices, &tampered_cells),
+            Err(Error::InvalidKzgProof(_))
+        ));
+        let other_commitment = kzg_settings
+            .blob_to_kzg_commitment(&generate_random_blob(&mut rand::thread_rng()))
+            .unwrap();
+        assert!(matches!(
+            kzg_settings.recover_and_verify(&other_commitment, &cell_indices, &some_cells),
+            Err(Error::InvalidKzgProof(_))
+        ));
+
+        let error = kzg_settings
+            .recover_cells_and_kzg_proofs(&cell_indices[1..], &some_cells[1..])
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::InsufficientCells {
+                required: 64,
+                got: 63
+            }
+        ));
+        assert!(matches!(
+            kzg_settings.recover_cells(&cell_indices[1..], &some_cells[1..]),
+            Err(Error::InsufficientCells { .. })
+        ));
+
+        let mut duplicate_indices = cell_indices.clone();
+        duplicate_indices[1] = duplicate_indices[0];
+        let error = kzg_settings
+            .recover_cells_and_kzg_proofs(&duplicate_indices, &some_cells)
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidArgument));
+    }
+
+    #[test]
+    fn test_find_invalid_cell_kzg_proof() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blob = generate_random_blob(&mut rand::thread_rng());
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let (cells, proofs) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+
+        let cell_indices = CellIndex::from_slice(&[0, 1, 2, 3]).unwrap();
+        let commitments = vec![commitment; cell_indices.len()];
+        let cells = cells[..4].to_vec();
+        let mut proofs = proofs[..4].to_vec();
+        assert_eq!(
+            kzg_settings
+                .find_invalid_cell_kzg_proof(&commitments, cell_indices, &cells, &proofs)
+                .unwrap(),
+            None
+        );
+
+        proofs.swap(2, 3);
+        assert_eq!(
+            kzg_settings
+                .find_invalid_cell_kzg_proof(&commitments, cell_indices, &cells, &proofs)
+                .unwrap(),
+            Some(2)
+        );
+        assert!(matches!(
+            kzg_settings.find_invalid_cell_kzg_proof(
+                &commitments,
+                cell_indices,
+                &cells,
+                &proofs[1..]
+            ),
+            Err(Error::MismatchLength(_))
+        ));
     }
 }
@@ -0,0 +1,98 @@
+//! Packing of arbitrary bytes into a single blob.
+//!
+//! Each field element holds 31 bytes of data after a zero leading byte, so every field element is
+//! below the BLS modulus whatever the data. The data is followed by a `0x80` marker byte and zero
+//! padding, which keeps trailing zeros of the data apart from the padding.
+
+use super::{Blob, Error, BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT, FIELD_ELEMENTS_PER_BLOB};
+use alloc::vec::Vec;
+
+const BYTES_PER_PACKED_ELEMENT: usize = BYTES_PER_FIELD_ELEMENT - 1;
+const END_MARKER: u8 = 0x80;
+
+/// The maximum number of bytes [`pack_to_blob`] can fit into a blob.
+pub const MAX_PACKED_BYTES: usize = FIELD_ELEMENTS_PER_BLOB * BYTES_PER_PACKED_ELEMENT - 1;
+
+/// Packs `data` into a blob of canonical field elements, failing if it is longer than
+/// [`MAX_PACKED_BYTES`].
+pub fn pack_to_blob(data: &[u8]) -> Result<Blob, Error> {
+    if data.len() > MAX_PACKED_BYTES {
+        return Err(Error::InvalidBytesLength(format!(
+            "Invalid byte length. Expected at most {} got {}",
+            MAX_PACKED_BYTES,
+            data.len(),
+        )));
+    }
+    let mut bytes = [0u8; BYTES_PER_BLOB];
+    let mut elements = bytes.chunks_exact_mut(BYTES_PER_FIELD_ELEMENT);
+    for (element, chunk) in elements.by_ref().zip(data.chunks(BYTES_PER_PACKED_ELEMENT)) {
+        element[1..=chunk.len()].copy_from_slice(chunk);
+    }
+    let marker = data.len() / BYTES_PER_PACKED_ELEMENT * BYTES_PER_FIELD_ELEMENT
+        + data.len() % BYTES_PER_PACKED_ELEMENT
+        + 1;
+    bytes[marker] = END_MARKER;
+    Ok(Blob::new(bytes))
+}
+
+/// Returns the data packed into `blob` by [`pack_to_blob`].
+///
+/// For a blob that was not created by [`pack_to_blob`] this returns the 31 data bytes of every
+/// field element, without trailing zeros and the marker byte before them if there is one.
+pub fn unpack_from_blob(blob: &Blob) -> Vec<u8> {
+    let mut data: Vec<u8> = blob
+        .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+        .flat_map(|element| &element[1..])
+        .copied()
+        .collect();
+    let len = data
+        .iter()
+        .rposition(|&byte| byte != 0)
+        .map_or(0, |i| i + 1);
+    data.truncate(len);
+    if data.last() == Some(&END_MARKER) {
+        data.pop();
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_pack_to_blob() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let data: Vec<u8> = (0..=u8::MAX).cycle().take(MAX_PACKED_BYTES).collect();
+        for len in [
+            0,
+            1,
+            30,
+            31,
+            32,
+            62,
+            1000,
+            MAX_PACKED_BYTES - 1,
+            MAX_PACKED_BYTES,
+        ] {
+            let blob = pack_to_blob(&data[..len]).unwrap();
+            assert_eq!(unpack_from_blob(&blob), &data[..len]);
+            assert!(kzg_settings.blob_to_kzg_commitment(&blob).is_ok());
+        }
+
+        let zeros = [0u8; 100];
+        assert_eq!(unpack_from_blob(&pack_to_blob(&zeros).unwrap()), zeros);
+        let blob = pack_to_blob(&[0xff; MAX_PACKED_BYTES]).unwrap();
+        assert!(blob
+            .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+            .all(|element| element[0] == 0));
+
+        assert!(matches!(
+            pack_to_blob(&vec![0u8; MAX_PACKED_BYTES + 1]),
+            Err(Error::InvalidBytesLength(_))
+        ));
+    }
+}
@@ -0,0 +1,412 @@
+//! Parallel variants of the batch operations, backed by rayon.
+
+use super::VerificationResult;
+use super::{Blob, Cell, Error, KZGCommitment, KZGProof, KZGSettings, CELLS_PER_EXT_BLOB};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ops::Range;
+use rayon::prelude::*;
+use rayon::ThreadPool;
+
+/// Batches with at most this many elements are verified on the calling thread.
+pub const PARALLEL_BATCH_THRESHOLD: usize = 8;
+
+/// Applies `f` to every item on the rayon thread pool, keeping the order of the items.
+///
+/// Fails with [`Error::InvalidBatchElement`] for the first item, by index, for which `f` fails.
+/// Items after a failing one are skipped once the failure is found.
+pub(crate) fn try_par_map<T, R, F>(items: &[T], f: F) -> Result<Vec<R>, Error>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> Result<R, Error> + Sync + Send,
+{
+    let mut results: Vec<Option<R>> = (0..items.len()).map(|_| None).collect();
+    let error = results
+        .par_iter_mut()
+        .zip(items.par_iter())
+        .enumerate()
+        .find_map_first(|(index, (result, item))| match f(item) {
+            Ok(value) => {
+                *result = Some(value);
+                None
+            }
+            Err(error) => Some(Error::InvalidBatchElement {
+                index,
+                error: Box::new(error),
+            }),
+        });
+    match error {
+        Some(error) => Err(error),
+        None => Ok(results
+            .into_iter()
+            .map(|result| result.expect("all items succeeded"))
+            .collect()),
+    }
+}
+
+impl KZGSettings {
+    /// Computes the commitments of all blobs on the rayon thread pool.
+    ///
+    /// This is [`blobs_to_kzg_commitments`](Self::blobs_to_kzg_commitments), which already runs
+    /// in parallel with the `rayon` feature, and fails in the same way.
+    pub fn blobs_to_kzg_commitments_par(
+        &self,
+        blobs: &[Blob],
+    ) -> Result<Vec<KZGCommitment>, Error> {
+        self.blobs_to_kzg_commitments(blobs)
+    }
+
+    /// Computes the blob proofs of all blob/commitment pairs on the rayon thread pool.
+    ///
+    /// The proofs are returned in the same order as the blobs.
+    pub fn compute_blob_kzg_proofs_par(
+        &self,
+        blobs: &[Blob],
+        commitments: &[KZGCommitment],
+    ) -> Result<Vec<KZGProof>, Error> {
+        if blobs.len() != commitments.len() {
+            return Err(Error::MismatchLength(format!(
+                "There are {} blobs and {} commitments",
+                blobs.len(),
+                commitments.len()
+            )));
+        }
+        blobs
+            .par_iter()
+            .zip(commitments.par_iter())
+            .map(|(blob, commitment)| self.compute_blob_kzg_proof(blob, commitment))
+            .collect()
+    }
+
+    /// Computes the cells and cell proofs of all blobs on the rayon thread pool.
+    ///
+    /// The proofs of a single blob are computed together by the C library, so the work is split
+    /// across blobs. The output for each blob is identical to
+    /// [`compute_cells_and_kzg_proofs`](Self::compute_cells_and_kzg_proofs) and is returned in
+    /// the same order as the blobs.
+    #[allow(clippy::type_complexity)]
+    pub fn compute_cells_and_kzg_proofs_par(
+        &self,
+        blobs: &[Blob],
+    ) -> Result<
+        Vec<(
+            Box<[Cell; CELLS_PER_EXT_BLOB]>,
+            Box<[KZGProof; CELLS_PER_EXT_BLOB]>,
+        )>,
+        Error,
+    > {
+        blobs
+            .par_iter()
+            .map(|blob| self.compute_cells_and_kzg_proofs(blob))
+            .collect()
+    }
+
+    /// Same as [`verify_blob_kzg_proof_batch`](Self::verify_blob_kzg_proof_batch), but splits
+    /// the batch into sub-batches which are verified on the rayon thread pool.
+    ///
+    /// Batches that are not larger than [`PARALLEL_BATCH_THRESHOLD`] are verified serially.
+    pub fn verify_blob_kzg_proof_batch_par(
+        &self,
+        blobs: &[Blob],
+        commitments: &[KZGCommitment],
+        proofs: &[KZGProof],
+    ) -> Result<VerificationResult, Error> {
+        self.verify_blob_kzg_proof_batch_par_with_threshold(
+            blobs,
+            commitments,
+            proofs,
+            PARALLEL_BATCH_THRESHOLD,
+        )
+    }
+
+    /// Same as [`verify_blob_kzg_proof_batch_par`](Self::verify_blob_kzg_proof_batch_par), but
+    /// with a custom threshold. Batches that are not larger than `threshold` are verified
+    /// serially, and no sub-batch is smaller than `threshold`.
+    pub fn verify_blob_kzg_proof_batch_par_with_threshold(
+        &self,
+        blobs: &[Blob],
+        commitments: &[KZGCommitment],
+        proofs: &[KZGProof],
+        threshold: usize,
+    ) -> Result<VerificationResult, Error> {
+        // Mismatched lengths are reported by the serial implementation.
+        if blobs.len() <= threshold
+            || blobs.len() != commitments.len()
+            || blobs.len() != proofs.len()
+        {
+            return self.verify_blob_kzg_proof_batch(blobs, commitments, proofs);
+        }
+
+        sub_batches(blobs.len(), threshold, rayon::current_num_threads())
+            .into_par_iter()
+            .map(|range| {
+                self.verify_blob_kzg_proof_batch(
+                    &blobs[range.clone()],
+                    &commitments[range.clone()],
+                    &proofs[range],
+                )
+            })
+            .try_reduce(
+                || true.into(),
+                |a, b| Ok((a.is_valid() && b.is_valid()).into()),
+            )
+    }
+
+    /// Same as [`blobs_to_kzg_commitments_par`](Self::blobs_to_kzg_commitments_par), but runs on
+    /// `pool` instead of the global thread pool.
+    pub fn blobs_to_kzg_commitments_in(
+        &self,
+        pool: &ThreadPool,
+        blobs: &[Blob],
+    ) -> Result<Vec<KZGCommitment>, Error> {
+        pool.install(|| self.blobs_to_kzg_commitments_par(blobs))
+    }
+
+    /// Same as [`compute_blob_kzg_proofs_par`](Self::compute_blob_kzg_proofs_par), but runs on
+    /// `pool` instead of the global thread pool.
+    pub fn compute_blob_kzg_proofs_in(
+        &self,
+        pool: &ThreadPool,
+        blobs: &[Blob],
+        commitments: &[KZGCommitment],
+    ) -> Result<Vec<KZGProof>, Error> {
+        pool.install(|| self.compute_blob_kzg_proofs_par(blobs, commitments))
+    }
+
+    /// Same as [`compute_cells_and_kzg_proofs_par`](Self::compute_cells_and_kzg_proofs_par), but
+    /// runs on `pool` instead of the global thread pool.
+    #[allow(clippy::type_complexity)]
+    pub fn compute_cells_and_kzg_proofs_in(
+        &self,
+        pool: &ThreadPool,
+        blobs: &[Blob],
+    ) -> Result<
+        Vec<(
+            Box<[Cell; CELLS_PER_EXT_BLOB]>,
+            Box<[KZGProof; CELLS_PER_EXT_BLOB]>,
+        )>,
+        Error,
+    > {
+        pool.install(|| self.compute_cells_and_kzg_proofs_par(blobs))
+    }
+
+    /// Same as [`verify_blob_kzg_proof_batch_par`](Self::verify_blob_kzg_proof_batch_par), but
+    /// runs on `pool` instead of the global thread pool. The batch is split according to the
+    /// number of threads of `pool`.
+    pub fn verify_blob_kzg_proof_batch_in(
+        &self,
+        pool: &ThreadPool,
+        blobs: &[Blob],
+        commitments: &[KZGCommitment],
+        proofs: &[KZGProof],
+    ) -> Result<VerificationResult, Error> {
+        pool.install(|| self.verify_blob_kzg_proof_batch_par(blobs, commitments, proofs))
+    }
+}
+
+/// Splits `len` elements into at most `threads` ranges of nearly equal size, none of which is
+/// smaller than `threshold` unless `len` is.
+fn sub_batches(len: usize, threshold: usize, threads: usize) -> Vec<Range<usize>> {
+    let count = (len / threshold.max(1)).min(threads).max(1);
+    let (size, remainder) = (len / count, len % count);
+    (0..count)
+        .map(|i| {
+            let start = i * size + i.min(remainder);
+            start..start + size + usize::from(i < remainder)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::generate_random_blob;
+    use super::super::*;
+
+    #[test]
+    fn test_blobs_to_kzg_commitments_and_proofs_par() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let mut rng = rand::thread_rng();
+        let blobs: Vec<Blob> = (0..4).map(|_| generate_random_blob(&mut rng)).collect();
+
+        let commitments = kzg_settings.blobs_to_kzg_commitments_par(&blobs).unwrap();
+        let expected_commitments: Vec<KZGCommitment> = blobs
+            .iter()
+            .map(|blob| kzg_settings.blob_to_kzg_commitment(blob).unwrap())
+            .collect();
+        assert_eq!(commitments, expected_commitments);
+
+        let proofs = kzg_settings
+            .compute_blob_kzg_proofs_par(&blobs, &commitments)
+            .unwrap();
+        let expected_proofs: Vec<KZGProof> = blobs
+            .iter()
+            .zip(commitments.iter())
+            .map(|(blob, commitment)| {
+                kzg_settings
+                    .compute_blob_kzg_proof(blob, commitment)
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(proofs, expected_proofs);
+
+        // Invalid blobs are reported.
+        let mut invalid_blobs = blobs.clone();
+        invalid_blobs[2] = Blob::new([0xff; BYTES_PER_BLOB]);
+        invalid_blobs[3] = Blob::new([0xff; BYTES_PER_BLOB]);
+        assert!(matches!(
+            kzg_settings.blobs_to_kzg_commitments_par(&invalid_blobs),
+            Err(Error::InvalidBatchElement { index: 2, .. })
+        ));
+
+        // Mismatched lengths are rejected.
+        let error = kzg_settings
+            .compute_blob_kzg_proofs_par(&blobs, &commitments[1..])
+            .unwrap_err();
+        assert!(matches!(error, Error::MismatchLength(_)));
+    }
+
+    #[test]
+    fn test_compute_cells_and_kzg_proofs_par() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let mut rng = rand::thread_rng();
+        let blobs: Vec<Blob> = (0..3).map(|_| generate_random_blob(&mut rng)).collect();
+
+        let parallel = kzg_settings
+            .compute_cells_and_kzg_proofs_par(&blobs)
+            .unwrap();
+        assert_eq!(parallel.len(), blobs.len());
+        for (blob, (cells, proofs)) in blobs.iter().zip(parallel.iter()) {
+            let (expected_cells, expected_proofs) =
+                kzg_settings.compute_cells_and_kzg_proofs(blob).unwrap();
+            assert_eq!(cells, &expected_cells);
+            assert_eq!(proofs, &expected_proofs);
+        }
+    }
+
+    #[test]
+    fn test_verify_blob_kzg_proof_batch_par() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+
+        let mut rng = rand::thread_rng();
+        let blobs: Vec<Blob> = (0..6).map(|_| generate_random_blob(&mut rng)).collect();
+        let commitments: Vec<KZGCommitment> = blobs
+            .iter()
+            .map(|blob| kzg_settings.blob_to_kzg_commitment(blob).unwrap())
+            .collect();
+        let mut proofs: Vec<KZGProof> = blobs
+            .iter()
+            .zip(commitments.iter())
+            .map(|(blob, commitment)| {
+                kzg_settings
+                    .compute_blob_kzg_proof(blob, commitment)
+                    .unwrap()
+            })
+            .collect();
+
+        let serial = kzg_settings
+            .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
+            .unwrap();
+        assert!(serial.is_valid());
+
+        for num_threads in [1, 2, 4] {
+            let pool = ::rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            let parallel = pool.install(|| {
+                kzg_settings.verify_blob_kzg_proof_batch_par_with_threshold(
+                    &blobs,
+                    &commitments,
+                    &proofs,
+                    1,
+                )
+            });
+            assert_eq!(parallel.unwrap(), serial);
+        }
+
+        // Corrupt a single proof by swapping in the proof of another blob.
+        proofs[4] = proofs[1];
+        assert!(!kzg_settings
+            .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
+            .unwrap()
+            .is_valid());
+        for num_threads in [1, 2, 4] {
+            let pool = ::rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            let parallel = pool.install(|| {
+                kzg_settings.verify_blob_kzg_proof_batch_par_with_threshold(
+                    &blobs,
+                    &commitments,
+                    &proofs,
+                    1,
+                )
+            });
+            assert!(!parallel.unwrap().is_valid());
+        }
+
+        // A custom pool returns the same result as the global one.
+        let pool = ::rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap();
+        assert!(!kzg_settings
+            .verify_blob_kzg_proof_batch_in(&pool, &blobs, &commitments, &proofs)
+            .unwrap()
+            .is_valid());
+        assert_eq!(
+            kzg_settings
+                .blobs_to_kzg_commitments_in(&pool, &blobs)
+                .unwrap(),
+            commitments
+        );
+        assert_eq!(
+            kzg_settings
+                .compute_blob_kzg_proofs_in(&pool, &blobs, &commitments)
+                .unwrap(),
+            kzg_settings
+                .compute_blob_kzg_proofs_par(&blobs, &commitments)
+                .unwrap()
+        );
+
+        // The default threshold also returns the same result.
+        assert!(!kzg_settings
+            .verify_blob_kzg_proof_batch_par(&blobs, &commitments, &proofs)
+            .unwrap()
+            .is_valid());
+
+        // Mismatched lengths are still rejected.
+        let error = kzg_settings
+            .verify_blob_kzg_proof_batch_par_with_threshold(&blobs, &commitments[1..], &proofs, 1)
+            .unwrap_err();
+        assert!(matches!(error, Error::MismatchLength(_)));
+    }
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_sub_batches() {
+        use super::sub_batches;
+        assert_eq!(sub_batches(9, 8, 4), [0..9]);
+        assert_eq!(sub_batches(16, 8, 4), [0..8, 8..16]);
+        assert_eq!(sub_batches(23, 8, 4), [0..12, 12..23]);
+        assert_eq!(sub_batches(64, 8, 3), [0..22, 22..43, 43..64]);
+        assert_eq!(sub_batches(5, 0, 8), [0..1, 1..2, 2..3, 3..4, 4..5]);
+        for len in 1..100 {
+            let ranges = sub_batches(len, 8, 6);
+            assert!(ranges.len() <= 6);
+            assert_eq!(ranges.first().unwrap().start, 0);
+            assert_eq!(ranges.last().unwrap().end, len);
+            assert!(ranges.windows(2).all(|w| w[0].end == w[1].start));
+            assert!(ranges.iter().all(|r| r.len() >= 8.min(len)));
+        }
+    }
+}
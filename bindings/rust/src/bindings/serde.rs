@@ -1,23 +1,102 @@
 //! Serde serialization and deserialization for the basic types in this crate.
+//!
+//! Human-readable formats use 0x-prefixed hex strings. Binary formats use a fixed-size tuple of
+//! the bytes, so that e.g. bincode stores exactly `SERIALIZED_SIZE` bytes without a length
+//! prefix.
 
-use crate::{Blob, Bytes32, Bytes48};
+use crate::{
+    Blob, Bytes32, Bytes48, Cell, KzgCommitment, KzgProof, BYTES_PER_BLOB, BYTES_PER_CELL,
+    BYTES_PER_COMMITMENT, BYTES_PER_PROOF,
+};
 use alloc::string::String;
 use alloc::vec::Vec;
-use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use core::fmt;
+use serde::{
+    de::{self, Error, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
-/// Serialize a byte vec as a hex string with 0x prefix
+macro_rules! impl_serialized_size {
+    ($($type:ty => $size:expr),* $(,)?) => {
+        $(
+            impl $type {
+                /// The number of bytes of the binary serialization in formats which encode
+                /// tuples without overhead, such as bincode.
+                pub const SERIALIZED_SIZE: usize = $size;
+            }
+        )*
+    };
+}
+
+impl_serialized_size! {
+    Blob => BYTES_PER_BLOB,
+    Bytes32 => 32,
+    Bytes48 => 48,
+    Cell => BYTES_PER_CELL,
+    KzgCommitment => BYTES_PER_COMMITMENT,
+    KzgProof => BYTES_PER_PROOF,
+}
+
+/// Serialize a byte vec as a hex string with 0x prefix in human-readable formats, and as a
+/// fixed-size tuple of bytes otherwise.
 pub fn serialize_bytes<S, T>(x: T, s: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
     T: AsRef<[u8]>,
 {
-    s.serialize_str(&format!("0x{}", hex::encode(x.as_ref())))
+    let bytes = x.as_ref();
+    if s.is_human_readable() {
+        s.serialize_str(&format!("0x{}", hex::encode(bytes)))
+    } else {
+        let mut tuple = s.serialize_tuple(bytes.len())?;
+        for byte in bytes {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+}
+
+/// Visitor collecting raw bytes from binary formats.
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a byte array")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
 }
 
-fn deserialize_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
-    let s = String::deserialize(deserializer)?;
-    let hex_bytes = s.strip_prefix("0x").unwrap_or(&s);
-    hex::decode(hex_bytes).map_err(Error::custom)
+/// Deserialize a hex string (with or without 0x prefix) in human-readable formats, and a tuple of
+/// `len` bytes otherwise.
+fn deserialize_bytes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    len: usize,
+) -> Result<Vec<u8>, D::Error> {
+    if deserializer.is_human_readable() {
+        let s = String::deserialize(deserializer)?;
+        let hex_bytes = s.strip_prefix("0x").unwrap_or(&s);
+        hex::decode(hex_bytes).map_err(Error::custom)
+    } else {
+        deserializer.deserialize_tuple(len, BytesVisitor)
+    }
 }
 
 impl Serialize for Blob {
@@ -31,7 +110,8 @@ impl Serialize for Blob {
 
 impl<'de> Deserialize<'de> for Blob {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Blob::from_bytes(&deserialize_hex(deserializer)?).map_err(Error::custom)
+        Blob::from_bytes(&deserialize_bytes(deserializer, Self::SERIALIZED_SIZE)?)
+            .map_err(Error::custom)
     }
 }
 
@@ -43,7 +123,8 @@ impl Serialize for Bytes48 {
 
 impl<'de> Deserialize<'de> for Bytes48 {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Bytes48::from_bytes(&deserialize_hex(deserializer)?).map_err(Error::custom)
+        Bytes48::from_bytes(&deserialize_bytes(deserializer, Self::SERIALIZED_SIZE)?)
+            .map_err(Error::custom)
     }
 }
 
@@ -61,7 +142,47 @@ impl<'de> Deserialize<'de> for Bytes32 {
     where
         D: Deserializer<'de>,
     {
-        Bytes32::from_bytes(&deserialize_hex(deserializer)?).map_err(Error::custom)
+        Bytes32::from_bytes(&deserialize_bytes(deserializer, Self::SERIALIZED_SIZE)?)
+            .map_err(Error::custom)
+    }
+}
+
+impl Serialize for Cell {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.bytes, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cell {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Cell::from_bytes(&deserialize_bytes(deserializer, Self::SERIALIZED_SIZE)?)
+            .map_err(Error::custom)
+    }
+}
+
+impl Serialize for KzgCommitment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.bytes, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KzgCommitment {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        KzgCommitment::from_bytes(&deserialize_bytes(deserializer, Self::SERIALIZED_SIZE)?)
+            .map_err(Error::custom)
+    }
+}
+
+impl Serialize for KzgProof {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.bytes, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KzgProof {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        KzgProof::from_bytes(&deserialize_bytes(deserializer, Self::SERIALIZED_SIZE)?)
+            .map_err(Error::custom)
     }
 }
 
@@ -82,7 +203,7 @@ mod tests {
     }
 
     fn trusted_setup_file() -> &'static Path {
-        Path::new("../../src/trusted_setup.txt")
+        Path::new("src/trusted_setup.txt")
     }
 
     #[test]
@@ -90,14 +211,15 @@ mod tests {
         // load setup so we can create commitments and blobs
         let trusted_setup_file = trusted_setup_file();
         assert!(trusted_setup_file.exists());
-        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file).unwrap();
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
 
         // generate blob, commitment, proof
         let mut rng = rand::thread_rng();
         let blob = generate_random_blob(&mut rng);
-        let commitment = KZGCommitment::blob_to_kzg_commitment(&blob, &kzg_settings).unwrap();
-        let proof =
-            KZGProof::compute_blob_kzg_proof(&blob, &commitment.to_bytes(), &kzg_settings).unwrap();
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let proof = kzg_settings
+            .compute_blob_kzg_proof(&blob, &commitment)
+            .unwrap();
 
         // check blob serialization
         let blob_serialized = serde_json::to_string(&blob).unwrap();
@@ -109,11 +231,30 @@ mod tests {
         let commitment_deserialized: Bytes48 =
             serde_json::from_str(&commitment_serialized).unwrap();
         assert_eq!(commitment.to_bytes(), commitment_deserialized);
+        let commitment_serialized = serde_json::to_string(&commitment).unwrap();
+        let commitment_deserialized: KZGCommitment =
+            serde_json::from_str(&commitment_serialized).unwrap();
+        assert_eq!(commitment.bytes, commitment_deserialized.bytes);
 
         // check proof serialization
         let proof_serialized = serde_json::to_string(&proof.to_bytes()).unwrap();
         let proof_deserialized: Bytes48 = serde_json::from_str(&proof_serialized).unwrap();
         assert_eq!(proof.to_bytes(), proof_deserialized);
+        let proof_serialized = serde_json::to_string(&proof).unwrap();
+        let proof_deserialized: KZGProof = serde_json::from_str(&proof_serialized).unwrap();
+        assert_eq!(proof.bytes, proof_deserialized.bytes);
+
+        // check cell serialization
+        let (cells, _) = kzg_settings.compute_cells_and_kzg_proofs(&blob).unwrap();
+        let cell_serialized = serde_json::to_string(&cells[0]).unwrap();
+        let cell_deserialized: Cell = serde_json::from_str(&cell_serialized).unwrap();
+        assert_eq!(cells[0], cell_deserialized);
+
+        // check bytes32 serialization
+        let z = Bytes32::new([1; 32]);
+        let z_serialized = serde_json::to_string(&z).unwrap();
+        let z_deserialized: Bytes32 = serde_json::from_str(&z_serialized).unwrap();
+        assert_eq!(z, z_deserialized);
     }
 
     #[test]
@@ -140,12 +281,12 @@ mod tests {
         // load setup so we can create a commitments
         let trusted_setup_file = trusted_setup_file();
         assert!(trusted_setup_file.exists());
-        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file).unwrap();
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
 
         // generate blob just to calculate a commitment
         let mut rng = rand::thread_rng();
         let blob = generate_random_blob(&mut rng);
-        let commitment = KZGCommitment::blob_to_kzg_commitment(&blob, &kzg_settings).unwrap();
+        let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
 
         // check blob serialization
         let blob_serialized = serde_json::to_string(&commitment.to_bytes()).unwrap();
@@ -159,4 +300,47 @@ mod tests {
         // check that it ends with a quote (sanity check)
         assert_eq!(chars.last().unwrap(), '"');
     }
+
+    #[test]
+    fn test_serialize_binary_roundtrip() {
+        // generate blob
+        let mut rng = rand::thread_rng();
+        let blob = generate_random_blob(&mut rng);
+
+        // check that binary formats store exactly the bytes
+        let blob_serialized = bincode::serialize(&blob).unwrap();
+        assert_eq!(blob_serialized.len(), BYTES_PER_BLOB);
+        assert_eq!(blob_serialized.len(), Blob::SERIALIZED_SIZE);
+        assert_eq!(&blob_serialized[..], blob.as_ref());
+        let blob_deserialized: Blob = bincode::deserialize(&blob_serialized).unwrap();
+        assert_eq!(blob, blob_deserialized);
+
+        let cell = Cell::new([2; BYTES_PER_CELL]);
+        let cell_serialized = bincode::serialize(&cell).unwrap();
+        assert_eq!(cell_serialized.len(), Cell::SERIALIZED_SIZE);
+        let cell_deserialized: Cell = bincode::deserialize(&cell_serialized).unwrap();
+        assert_eq!(cell, cell_deserialized);
+
+        let bytes48 = Bytes48::new([3; 48]);
+        let bytes48_serialized = bincode::serialize(&bytes48).unwrap();
+        assert_eq!(bytes48_serialized.len(), Bytes48::SERIALIZED_SIZE);
+        let bytes48_deserialized: Bytes48 = bincode::deserialize(&bytes48_serialized).unwrap();
+        assert_eq!(bytes48, bytes48_deserialized);
+    }
+
+    #[test]
+    fn test_deserialize_wrong_length() {
+        // human-readable formats
+        assert!(serde_json::from_str::<Blob>("\"0x00\"").is_err());
+        assert!(serde_json::from_str::<Cell>("\"0x00\"").is_err());
+        assert!(serde_json::from_str::<Bytes32>(&format!("\"0x{}\"", "00".repeat(33))).is_err());
+        assert!(serde_json::from_str::<Bytes48>(&format!("\"0x{}\"", "00".repeat(47))).is_err());
+        assert!(serde_json::from_str::<KZGCommitment>("\"0x\"").is_err());
+        assert!(serde_json::from_str::<KZGProof>("\"0xzz\"").is_err());
+
+        // binary formats
+        let serialized = bincode::serialize(&Bytes32::new([0; 32])).unwrap();
+        assert!(bincode::deserialize::<Bytes48>(&serialized).is_err());
+        assert!(bincode::deserialize::<Blob>(&serialized).is_err());
+    }
 }
@@ -0,0 +1,185 @@
+//! A builder for loading trusted setups with named options.
+
+use super::{Error, KZGSettings, Precompute};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{boxed::Box, io::Read, path::PathBuf};
+
+/// Where a [`KzgSettingsBuilder`] loads the trusted setup from.
+enum Source<'a> {
+    None,
+    Bytes(&'a [u8]),
+    #[cfg(feature = "std")]
+    File(PathBuf),
+    #[cfg(feature = "std")]
+    Reader(Box<dyn Read + 'a>),
+    #[cfg(feature = "default-setup")]
+    Default,
+}
+
+/// Collects the options for loading a trusted setup, as an alternative to the positional
+/// arguments of the `KZGSettings` constructors.
+///
+/// Exactly one source must be set before calling [`build`](Self::build). Setting another source
+/// replaces the previous one.
+pub struct KzgSettingsBuilder<'a> {
+    source: Source<'a>,
+    precompute: Precompute,
+    #[cfg(feature = "memmap2")]
+    mmap: bool,
+}
+
+impl KZGSettings {
+    /// Returns a builder for loading settings.
+    pub fn builder<'a>() -> KzgSettingsBuilder<'a> {
+        KzgSettingsBuilder::new()
+    }
+}
+
+impl Default for KzgSettingsBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> KzgSettingsBuilder<'a> {
+    pub fn new() -> Self {
+        Self {
+            source: Source::None,
+            precompute: Precompute::NONE,
+            #[cfg(feature = "memmap2")]
+            mmap: false,
+        }
+    }
+
+    /// Sets the precompute level, which is [`Precompute::NONE`] by default.
+    pub fn precompute(mut self, precompute: impl Into<Precompute>) -> Self {
+        self.precompute = precompute.into();
+        self
+    }
+
+    /// Loads the contents of a trusted setup file, as in
+    /// [`KZGSettings::from_bytes`].
+    pub fn bytes(mut self, setup_bytes: &'a [u8]) -> Self {
+        self.source = Source::Bytes(setup_bytes);
+        self
+    }
+
+    /// Loads a trusted setup file, as in [`KZGSettings::load_trusted_setup_file`].
+    #[cfg(feature = "std")]
+    pub fn file(mut self, file_path: impl Into<PathBuf>) -> Self {
+        self.source = Source::File(file_path.into());
+        self
+    }
+
+    /// Reads a trusted setup file from `reader`, as in
+    /// [`KZGSettings::load_trusted_setup_from_reader`].
+    #[cfg(feature = "std")]
+    pub fn reader(mut self, reader: impl Read + 'a) -> Self {
+        self.source = Source::Reader(Box::new(reader));
+        self
+    }
+
+    /// Loads the embedded Ethereum mainnet trusted setup, as in [`KZGSettings::load_default`].
+    #[cfg(feature = "default-setup")]
+    pub fn default_setup(mut self) -> Self {
+        self.source = Source::Default;
+        self
+    }
+
+    /// Maps a trusted setup file into memory instead of reading it, as in
+    /// [`KZGSettings::load_trusted_setup_mmap`]. Only applies to [`file`](Self::file).
+    #[cfg(feature = "memmap2")]
+    pub fn mmap(mut self, mmap: bool) -> Self {
+        self.mmap = mmap;
+        self
+    }
+
+    /// Loads the settings from the configured source.
+    pub fn build(self) -> Result<KZGSettings, Error> {
+        match self.source {
+            Source::None => Err(Error::InvalidTrustedSetup(
+                "No trusted setup source was set".into(),
+            )),
+            Source::Bytes(bytes) => KZGSettings::from_bytes(bytes, self.precompute),
+            #[cfg(feature = "memmap2")]
+            Source::File(path) if self.mmap => {
+                KZGSettings::load_trusted_setup_mmap(&path, self.precompute)
+            }
+            #[cfg(feature = "std")]
+            Source::File(path) => KZGSettings::load_trusted_setup_file(&path, self.precompute),
+            #[cfg(feature = "std")]
+            Source::Reader(reader) => {
+                KZGSettings::load_trusted_setup_from_reader(reader, self.precompute)
+            }
+            #[cfg(feature = "default-setup")]
+            Source::Default => KZGSettings::load_default(self.precompute),
+        }
+    }
+}
+
+impl fmt::Debug for KzgSettingsBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = match &self.source {
+            Source::None => "none",
+            Source::Bytes(_) => "bytes",
+            #[cfg(feature = "std")]
+            Source::File(_) => "file",
+            #[cfg(feature = "std")]
+            Source::Reader(_) => "reader",
+            #[cfg(feature = "default-setup")]
+            Source::Default => "default",
+        };
+        f.debug_struct("KzgSettingsBuilder")
+            .field("source", &source)
+            .field("precompute", &self.precompute)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_settings_builder() {
+        const TRUSTED_SETUP: &[u8] = include_bytes!("../../../../src/trusted_setup.txt");
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let direct = KZGSettings::load_trusted_setup_file(trusted_setup_file, 2).unwrap();
+        let blob = Blob::new([1; BYTES_PER_BLOB]);
+        let commitment = direct.blob_to_kzg_commitment(&blob).unwrap();
+
+        let builders = [
+            KZGSettings::builder().file(trusted_setup_file),
+            KZGSettings::builder().bytes(TRUSTED_SETUP),
+            KZGSettings::builder().reader(TRUSTED_SETUP),
+            #[cfg(feature = "default-setup")]
+            KZGSettings::builder().default_setup(),
+            #[cfg(feature = "memmap2")]
+            KZGSettings::builder().file(trusted_setup_file).mmap(true),
+        ];
+        for builder in builders {
+            let settings = builder.precompute(2).build().unwrap();
+            assert_eq!(settings.precompute(), Precompute::level(2).unwrap());
+            assert_eq!(settings.to_binary(), direct.to_binary());
+            assert_eq!(settings.blob_to_kzg_commitment(&blob).unwrap(), commitment);
+        }
+
+        assert_eq!(
+            KZGSettings::builder()
+                .bytes(TRUSTED_SETUP)
+                .build()
+                .unwrap()
+                .precompute(),
+            Precompute::NONE
+        );
+        assert!(matches!(
+            KZGSettings::builder().precompute(2).build(),
+            Err(Error::InvalidTrustedSetup(_))
+        ));
+        assert!(KZGSettings::builder()
+            .file("does/not/exist")
+            .build()
+            .is_err());
+    }
+}
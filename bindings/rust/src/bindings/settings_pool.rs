@@ -0,0 +1,153 @@
+//! A fixed set of trusted setup copies lent out to one thread at a time.
+
+use super::{Error, KZGSettings, Precompute};
+use core::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::vec::Vec;
+
+/// Holds `n` copies of the same trusted setup, each of which is lent to one caller at a time by
+/// [`acquire`](Self::acquire).
+///
+/// All operations on [`KZGSettings`] take `&self` and the settings are `Sync`, so a single copy
+/// shared with [`into_shared`](KZGSettings::into_shared) is enough for most uses. The pool is for
+/// callers which need exclusive access to a copy, and it costs `n` times the memory of the setup,
+/// including any precomputed tables.
+#[derive(Debug)]
+pub struct KzgSettingsPool {
+    settings: Mutex<Vec<KZGSettings>>,
+    available: Condvar,
+}
+
+impl KzgSettingsPool {
+    /// Loads the trusted setup file once and clones it into a pool of `n` copies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn new(
+        n: usize,
+        file_path: &Path,
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
+        let settings = KZGSettings::load_trusted_setup_file(file_path, precompute)?;
+        Ok(Self::from_settings(settings, n))
+    }
+
+    /// Creates a pool of `n` copies of `settings`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn from_settings(settings: KZGSettings, n: usize) -> Self {
+        assert!(n > 0, "a settings pool needs at least one copy");
+        let mut copies = Vec::with_capacity(n);
+        copies.extend((1..n).map(|_| settings.clone()));
+        copies.push(settings);
+        Self {
+            settings: Mutex::new(copies),
+            available: Condvar::new(),
+        }
+    }
+
+    /// Takes a copy of the settings out of the pool, blocking until one is returned if all of
+    /// them are in use. The copy is returned to the pool when the guard is dropped.
+    pub fn acquire(&self) -> PoolGuard<'_> {
+        let mut settings = self.settings.lock().unwrap_or_else(PoisonError::into_inner);
+        loop {
+            if let Some(settings) = settings.pop() {
+                return PoolGuard {
+                    pool: self,
+                    settings: Some(settings),
+                };
+            }
+            settings = self
+                .available
+                .wait(settings)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Takes a copy of the settings out of the pool if one is available.
+    pub fn try_acquire(&self) -> Option<PoolGuard<'_>> {
+        let settings = self
+            .settings
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop()?;
+        Some(PoolGuard {
+            pool: self,
+            settings: Some(settings),
+        })
+    }
+}
+
+/// A copy of the settings borrowed from a [`KzgSettingsPool`], which is returned on drop.
+#[derive(Debug)]
+pub struct PoolGuard<'a> {
+    pool: &'a KzgSettingsPool,
+    settings: Option<KZGSettings>,
+}
+
+impl Deref for PoolGuard<'_> {
+    type Target = KZGSettings;
+
+    fn deref(&self) -> &Self::Target {
+        self.settings
+            .as_ref()
+            .expect("settings are only taken on drop")
+    }
+}
+
+impl DerefMut for PoolGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.settings
+            .as_mut()
+            .expect("settings are only taken on drop")
+    }
+}
+
+impl Drop for PoolGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(settings) = self.settings.take() {
+            self.pool
+                .settings
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(settings);
+            self.pool.available.notify_one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::KzgSettingsPool;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_settings_pool() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let pool = Arc::new(KzgSettingsPool::new(2, trusted_setup_file, 0).unwrap());
+        let blob = Blob::default();
+
+        let first = pool.acquire();
+        let second = pool.acquire();
+        assert!(pool.try_acquire().is_none());
+        let commitment = first.blob_to_kzg_commitment(&blob).unwrap();
+        assert_eq!(second.blob_to_kzg_commitment(&blob).unwrap(), commitment);
+
+        // A waiting thread gets the copy as soon as one is dropped.
+        let waiter = std::thread::spawn({
+            let pool = Arc::clone(&pool);
+            move || pool.acquire().blob_to_kzg_commitment(&blob).unwrap()
+        });
+        drop(first);
+        assert_eq!(waiter.join().unwrap(), commitment);
+        drop(second);
+        assert!(pool.try_acquire().is_some());
+
+        assert!(KzgSettingsPool::new(1, Path::new("does/not/exist"), 0).is_err());
+    }
+}
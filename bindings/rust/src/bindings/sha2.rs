@@ -0,0 +1,97 @@
+//! Checksums of trusted setup files and loaded settings, backed by sha2.
+
+use super::{Bytes32, Error, KZGSettings, Precompute};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// The SHA-256 hash of the Ethereum mainnet trusted setup file, `src/trusted_setup.txt`.
+pub const MAINNET_TRUSTED_SETUP_SHA256: [u8; 32] = [
+    0xd3, 0x9b, 0x9f, 0x2d, 0x04, 0x7c, 0xc9, 0xdc, 0xa2, 0xde, 0x58, 0xf2, 0x64, 0xb6, 0xa0, 0x94,
+    0x48, 0xcc, 0xd3, 0x4d, 0xb9, 0x67, 0x88, 0x1a, 0x67, 0x13, 0xea, 0xca, 0xcf, 0x0f, 0x26, 0xb7,
+];
+
+impl KZGSettings {
+    /// Same as [`load_trusted_setup_file`](Self::load_trusted_setup_file), but fails with
+    /// [`Error::TrustedSetupChecksum`] unless the file is the Ethereum mainnet trusted setup.
+    pub fn load_trusted_setup_file_safe(
+        file_path: &Path,
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
+        Self::load_trusted_setup_file_with_checksum(
+            file_path,
+            &MAINNET_TRUSTED_SETUP_SHA256,
+            precompute,
+        )
+    }
+
+    /// Same as [`load_trusted_setup_file`](Self::load_trusted_setup_file), but fails with
+    /// [`Error::TrustedSetupChecksum`] unless the SHA-256 hash of the file is `expected_sha256`.
+    ///
+    /// The file is read once and the checked contents are parsed, so the file cannot change
+    /// between the check and the load.
+    pub fn load_trusted_setup_file_with_checksum(
+        file_path: &Path,
+        expected_sha256: &[u8; 32],
+        precompute: impl Into<Precompute>,
+    ) -> Result<Self, Error> {
+        let contents = std::fs::read(file_path).map_err(|e| {
+            Error::InvalidTrustedSetup(format!(
+                "Failed to read trusted setup file {file_path:?}: {e}"
+            ))
+        })?;
+        let got: [u8; 32] = Sha256::digest(&contents).into();
+        if &got != expected_sha256 {
+            return Err(Error::TrustedSetupChecksum {
+                expected: *expected_sha256,
+                got,
+            });
+        }
+        Self::from_bytes(&contents, precompute)
+    }
+
+    /// Returns the SHA-256 hash of the [`to_binary`](Self::to_binary) encoding of these settings,
+    /// which covers the G1 and G2 points and the precompute level.
+    ///
+    /// Settings loaded from the same trusted setup with the same precompute level have the same
+    /// fingerprint, regardless of the format they were loaded from.
+    pub fn fingerprint(&self) -> Bytes32 {
+        let hash: [u8; 32] = Sha256::digest(self.to_binary()).into();
+        Bytes32::new(hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::MAINNET_TRUSTED_SETUP_SHA256;
+
+    #[test]
+    fn test_load_trusted_setup_file_safe() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        assert!(KZGSettings::load_trusted_setup_file_safe(trusted_setup_file, 0).is_ok());
+
+        let expected = [0; 32];
+        let error =
+            KZGSettings::load_trusted_setup_file_with_checksum(trusted_setup_file, &expected, 0)
+                .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::TrustedSetupChecksum { expected: e, got }
+                if e == expected && got == MAINNET_TRUSTED_SETUP_SHA256
+        ));
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let reloaded = KZGSettings::load_trusted_setup_file_safe(trusted_setup_file, 0).unwrap();
+        assert_eq!(settings.fingerprint(), reloaded.fingerprint());
+        let from_binary = KZGSettings::from_binary(&settings.to_binary()).unwrap();
+        assert_eq!(settings.fingerprint(), from_binary.fingerprint());
+
+        let precomputed = KZGSettings::load_trusted_setup_file(trusted_setup_file, 2).unwrap();
+        assert_ne!(settings.fingerprint(), precomputed.fingerprint());
+    }
+}
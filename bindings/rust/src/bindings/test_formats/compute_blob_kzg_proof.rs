@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use crate::{Blob, Bytes48, Error};
+use crate::{Blob, Bytes48, Error, KzgCommitment};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -14,8 +14,8 @@ impl Input<'_> {
         Blob::from_hex(self.blob)
     }
 
-    pub fn get_commitment(&self) -> Result<Bytes48, Error> {
-        Bytes48::from_hex(self.commitment)
+    pub fn get_commitment(&self) -> Result<KzgCommitment, Error> {
+        KzgCommitment::from_hex(self.commitment)
     }
 }
 
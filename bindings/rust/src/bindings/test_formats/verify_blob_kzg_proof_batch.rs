@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use crate::{Blob, Bytes48, Error};
+use crate::{Blob, Error, KzgCommitment, KzgProof};
 use alloc::string::String;
 use alloc::vec::Vec;
 use serde::Deserialize;
@@ -22,18 +22,18 @@ impl Input {
         Ok(v)
     }
 
-    pub fn get_commitments(&self) -> Result<Vec<Bytes48>, Error> {
+    pub fn get_commitments(&self) -> Result<Vec<KzgCommitment>, Error> {
         self.commitments
             .iter()
-            .map(|s| Bytes48::from_hex(s))
-            .collect::<Result<Vec<Bytes48>, Error>>()
+            .map(|s| KzgCommitment::from_hex(s))
+            .collect::<Result<Vec<KzgCommitment>, Error>>()
     }
 
-    pub fn get_proofs(&self) -> Result<Vec<Bytes48>, Error> {
+    pub fn get_proofs(&self) -> Result<Vec<KzgProof>, Error> {
         self.proofs
             .iter()
-            .map(|s| Bytes48::from_hex(s))
-            .collect::<Result<Vec<Bytes48>, Error>>()
+            .map(|s| KzgProof::from_hex(s))
+            .collect::<Result<Vec<KzgProof>, Error>>()
     }
 }
 
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use crate::{Bytes48, Cell, Error};
+use crate::{Cell, Error, KzgCommitment, KzgProof};
 use alloc::string::String;
 use alloc::vec::Vec;
 use serde::Deserialize;
@@ -14,11 +14,11 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn get_commitments(&self) -> Result<Vec<Bytes48>, Error> {
+    pub fn get_commitments(&self) -> Result<Vec<KzgCommitment>, Error> {
         self.commitments
             .iter()
-            .map(|s| Bytes48::from_hex(s))
-            .collect::<Result<Vec<Bytes48>, Error>>()
+            .map(|s| KzgCommitment::from_hex(s))
+            .collect::<Result<Vec<KzgCommitment>, Error>>()
     }
 
     pub fn get_cell_indices(&self) -> Result<Vec<u64>, Error> {
@@ -32,11 +32,11 @@ impl Input {
             .collect::<Result<Vec<Cell>, Error>>()
     }
 
-    pub fn get_proofs(&self) -> Result<Vec<Bytes48>, Error> {
+    pub fn get_proofs(&self) -> Result<Vec<KzgProof>, Error> {
         self.proofs
             .iter()
-            .map(|s| Bytes48::from_hex(s))
-            .collect::<Result<Vec<Bytes48>, Error>>()
+            .map(|s| KzgProof::from_hex(s))
+            .collect::<Result<Vec<KzgProof>, Error>>()
     }
 }
 
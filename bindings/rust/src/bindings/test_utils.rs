@@ -0,0 +1,136 @@
+//! Helpers for generating valid inputs in tests.
+
+use super::{Blob, KZGCommitment, KZGProof, KZGSettings, BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT};
+use rand_core::{impls, RngCore};
+
+impl Blob {
+    /// Returns a random blob in which every field element is canonical, so that it is always
+    /// accepted by the KZG functions.
+    ///
+    /// The blob only depends on the output of `rng`, so a seeded generator always yields the same
+    /// blob.
+    pub fn random(rng: &mut impl RngCore) -> Self {
+        let mut bytes = [0u8; BYTES_PER_BLOB];
+        rng.fill_bytes(&mut bytes);
+        let mut scalar = blst::blst_scalar::default();
+        for chunk in bytes.chunks_exact_mut(BYTES_PER_FIELD_ELEMENT) {
+            // SAFETY: the chunk is `BYTES_PER_FIELD_ELEMENT` bytes long, which is the size of a
+            // scalar.
+            unsafe {
+                blst::blst_scalar_from_be_bytes(&mut scalar, chunk.as_ptr(), chunk.len());
+                blst::blst_bendian_from_scalar(chunk.as_mut_ptr(), &scalar);
+            }
+        }
+        Self::new(bytes)
+    }
+
+    /// Returns a random blob generated from `seed` with [`Blob::random`].
+    ///
+    /// The blob is the same for the same seed across runs, but may change with the version of
+    /// `rand`, as its `SmallRng` is not portable.
+    #[cfg(feature = "rand")]
+    pub fn random_with_seed(seed: u64) -> Self {
+        use rand::{rngs::SmallRng, SeedableRng};
+        Self::random(&mut SmallRng::seed_from_u64(seed))
+    }
+}
+
+/// The SplitMix64 generator, which is enough to spread a fixed seed over a blob.
+struct SplitMix64(u64);
+
+impl RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Returns a fixed blob of pseudorandom canonical field elements, which is the same on every
+/// run and with every version of this crate.
+pub fn sample_blob() -> Blob {
+    Blob::random(&mut SplitMix64(0))
+}
+
+/// Computes the commitment to `blob` and the proof of the blob for it.
+///
+/// # Panics
+///
+/// Panics if `blob` is not valid.
+pub fn sample_commitment_and_proof(
+    blob: &Blob,
+    kzg_settings: &KZGSettings,
+) -> (KZGCommitment, KZGProof) {
+    let commitment = kzg_settings
+        .blob_to_kzg_commitment(blob)
+        .expect("blob is valid");
+    let proof = kzg_settings
+        .compute_blob_kzg_proof(blob, &commitment)
+        .expect("blob and commitment are valid");
+    (commitment, proof)
+}
+
+/// Asserts that `proof` is the proof of `blob` for `commitment`, panicking with the error if the
+/// inputs are malformed.
+///
+/// All arguments are references, as for
+/// [`verify_blob_kzg_proof`](crate::KzgSettings::verify_blob_kzg_proof).
+#[macro_export]
+macro_rules! assert_blob_verifies {
+    ($blob:expr, $commitment:expr, $proof:expr, $kzg_settings:expr $(,)?) => {
+        match $kzg_settings.verify_blob_kzg_proof($blob, $commitment, $proof) {
+            Ok(result) => assert!(result.is_valid(), "blob proof does not verify"),
+            Err(error) => panic!("blob proof verification failed: {error}"),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::{sample_blob, sample_commitment_and_proof};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_random_blob() {
+        let blob = Blob::random(&mut StdRng::seed_from_u64(7));
+        assert_eq!(blob, Blob::random(&mut StdRng::seed_from_u64(7)));
+        assert_ne!(blob, Blob::random(&mut StdRng::seed_from_u64(8)));
+        assert!(validate_blob_field_elements(&blob).is_ok());
+        #[cfg(feature = "rand")]
+        assert_eq!(Blob::random_with_seed(7), Blob::random_with_seed(7));
+
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let (commitment, proof) = sample_commitment_and_proof(&blob, &kzg_settings);
+        crate::assert_blob_verifies!(&blob, &commitment, &proof, kzg_settings);
+    }
+
+    #[test]
+    fn test_sample_blob() {
+        let blob = sample_blob();
+        assert_eq!(blob, sample_blob());
+        assert!(validate_blob_field_elements(&blob).is_ok());
+        assert_ne!(blob.field_elements().next(), blob.field_elements().nth(1));
+
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let (commitment, proof) = sample_commitment_and_proof(&blob, &kzg_settings);
+        crate::assert_blob_verifies!(&blob, &commitment, &proof, kzg_settings);
+    }
+}
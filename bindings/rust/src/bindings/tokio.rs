@@ -0,0 +1,232 @@
+//! Async counterparts of the [`KZGSettings`] methods.
+//!
+//! Each function copies its inputs and runs the corresponding synchronous method on tokio's
+//! blocking thread pool, so that the expensive KZG operations do not stall the async executor.
+//! The settings are passed as an `Arc` which is cloned into the blocking task.
+
+use super::{Blob, Bytes32, Cell, Error, KZGCommitment, KZGProof, KZGSettings, KzgProofWithEval};
+use super::{CellIndex, VerificationResult, CELLS_PER_EXT_BLOB};
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+/// Runs `f` on the blocking thread pool and waits for its result.
+///
+/// Panics inside `f` are propagated to the caller.
+async fn spawn_blocking<F, T>(f: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    match ::tokio::task::spawn_blocking(f).await {
+        Ok(result) => result,
+        Err(e) => std::panic::resume_unwind(e.into_panic()),
+    }
+}
+
+/// Async version of [`KZGSettings::blob_to_kzg_commitment`].
+pub async fn blob_to_kzg_commitment_async(
+    blob: &Blob,
+    settings: Arc<KZGSettings>,
+) -> Result<KZGCommitment, Error> {
+    let blob = Box::new(blob.clone());
+    spawn_blocking(move || settings.blob_to_kzg_commitment(&blob)).await
+}
+
+/// Async version of [`KZGSettings::compute_kzg_proof`].
+pub async fn compute_kzg_proof_async(
+    blob: &Blob,
+    z_bytes: &Bytes32,
+    settings: Arc<KZGSettings>,
+) -> Result<KzgProofWithEval, Error> {
+    let blob = Box::new(blob.clone());
+    let z_bytes = *z_bytes;
+    spawn_blocking(move || settings.compute_kzg_proof(&blob, &z_bytes)).await
+}
+
+/// Async version of [`KZGSettings::compute_blob_kzg_proof`].
+pub async fn compute_blob_kzg_proof_async(
+    blob: &Blob,
+    commitment: &KZGCommitment,
+    settings: Arc<KZGSettings>,
+) -> Result<KZGProof, Error> {
+    let blob = Box::new(blob.clone());
+    let commitment = *commitment;
+    spawn_blocking(move || settings.compute_blob_kzg_proof(&blob, &commitment)).await
+}
+
+/// Async version of [`KZGSettings::verify_kzg_proof`].
+pub async fn verify_kzg_proof_async(
+    commitment: &KZGCommitment,
+    z_bytes: &Bytes32,
+    y_bytes: &Bytes32,
+    proof: &KZGProof,
+    settings: Arc<KZGSettings>,
+) -> Result<VerificationResult, Error> {
+    let (commitment, z_bytes, y_bytes, proof) = (*commitment, *z_bytes, *y_bytes, *proof);
+    spawn_blocking(move || settings.verify_kzg_proof(&commitment, &z_bytes, &y_bytes, &proof)).await
+}
+
+/// Async version of [`KZGSettings::verify_blob_kzg_proof`].
+pub async fn verify_blob_kzg_proof_async(
+    blob: &Blob,
+    commitment: &KZGCommitment,
+    proof: &KZGProof,
+    settings: Arc<KZGSettings>,
+) -> Result<VerificationResult, Error> {
+    let blob = Box::new(blob.clone());
+    let (commitment, proof) = (*commitment, *proof);
+    spawn_blocking(move || settings.verify_blob_kzg_proof(&blob, &commitment, &proof)).await
+}
+
+/// Async version of [`KZGSettings::verify_blob_kzg_proof_batch`].
+pub async fn verify_blob_kzg_proof_batch_async(
+    blobs: &[Blob],
+    commitments: &[KZGCommitment],
+    proofs: &[KZGProof],
+    settings: Arc<KZGSettings>,
+) -> Result<VerificationResult, Error> {
+    let blobs = blobs.to_vec();
+    let commitments = commitments.to_vec();
+    let proofs = proofs.to_vec();
+    spawn_blocking(move || settings.verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs))
+        .await
+}
+
+/// Async version of [`KZGSettings::compute_cells_and_kzg_proofs`].
+#[allow(clippy::type_complexity)]
+pub async fn compute_cells_and_kzg_proofs_async(
+    blob: &Blob,
+    settings: Arc<KZGSettings>,
+) -> Result<
+    (
+        Box<[Cell; CELLS_PER_EXT_BLOB]>,
+        Box<[KZGProof; CELLS_PER_EXT_BLOB]>,
+    ),
+    Error,
+> {
+    let blob = Box::new(blob.clone());
+    spawn_blocking(move || settings.compute_cells_and_kzg_proofs(&blob)).await
+}
+
+/// Async version of [`KZGSettings::recover_cells_and_kzg_proofs`].
+#[allow(clippy::type_complexity)]
+pub async fn recover_cells_and_kzg_proofs_async(
+    cell_indices: &[CellIndex],
+    cells: &[Cell],
+    settings: Arc<KZGSettings>,
+) -> Result<
+    (
+        Box<[Cell; CELLS_PER_EXT_BLOB]>,
+        Box<[KZGProof; CELLS_PER_EXT_BLOB]>,
+    ),
+    Error,
+> {
+    let cell_indices = cell_indices.to_vec();
+    let cells: Vec<Cell> = cells.to_vec();
+    spawn_blocking(move || settings.recover_cells_and_kzg_proofs(&cell_indices, &cells)).await
+}
+
+/// Async version of [`KZGSettings::verify_cell_kzg_proof_batch`].
+pub async fn verify_cell_kzg_proof_batch_async(
+    commitments: &[KZGCommitment],
+    cell_indices: &[CellIndex],
+    cells: &[Cell],
+    proofs: &[KZGProof],
+    settings: Arc<KZGSettings>,
+) -> Result<VerificationResult, Error> {
+    let commitments = commitments.to_vec();
+    let cell_indices = cell_indices.to_vec();
+    let cells = cells.to_vec();
+    let proofs = proofs.to_vec();
+    spawn_blocking(move || {
+        settings.verify_cell_kzg_proof_batch(&commitments, &cell_indices, &cells, &proofs)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+
+    fn kzg_settings() -> Arc<KZGSettings> {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        Arc::new(KZGSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap())
+    }
+
+    #[::tokio::test(crate = "::tokio")]
+    async fn test_async_matches_sync() {
+        let kzg_settings = kzg_settings();
+        let blob = Blob::new([0; BYTES_PER_BLOB]);
+        let z = Bytes32::new([1; 32]);
+
+        let commitment = blob_to_kzg_commitment_async(&blob, kzg_settings.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            commitment,
+            kzg_settings.blob_to_kzg_commitment(&blob).unwrap()
+        );
+
+        let KzgProofWithEval { proof, y } =
+            compute_kzg_proof_async(&blob, &z, kzg_settings.clone())
+                .await
+                .unwrap();
+        assert_eq!(
+            KzgProofWithEval { proof, y },
+            kzg_settings.compute_kzg_proof(&blob, &z).unwrap()
+        );
+        assert!(
+            verify_kzg_proof_async(&commitment, &z, &y, &proof, kzg_settings.clone())
+                .await
+                .unwrap()
+                .is_valid()
+        );
+
+        let proof = compute_blob_kzg_proof_async(&blob, &commitment, kzg_settings.clone())
+            .await
+            .unwrap();
+        assert!(
+            verify_blob_kzg_proof_async(&blob, &commitment, &proof, kzg_settings.clone())
+                .await
+                .unwrap()
+                .is_valid()
+        );
+        assert!(verify_blob_kzg_proof_batch_async(
+            std::slice::from_ref(&blob),
+            &[commitment],
+            &[proof],
+            kzg_settings.clone()
+        )
+        .await
+        .unwrap()
+        .is_valid());
+
+        let (cells, proofs) = compute_cells_and_kzg_proofs_async(&blob, kzg_settings.clone())
+            .await
+            .unwrap();
+        let cell_indices: Vec<u64> = (0..CELLS_PER_EXT_BLOB as u64 / 2).collect();
+        let (recovered_cells, recovered_proofs) = recover_cells_and_kzg_proofs_async(
+            CellIndex::from_slice(&cell_indices).unwrap(),
+            &cells[..CELLS_PER_EXT_BLOB / 2],
+            kzg_settings.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(recovered_cells, cells);
+        assert_eq!(recovered_proofs, proofs);
+
+        assert!(verify_cell_kzg_proof_batch_async(
+            &[commitment, commitment],
+            CellIndex::from_slice(&[0, 1]).unwrap(),
+            &cells[..2],
+            &proofs[..2],
+            kzg_settings.clone()
+        )
+        .await
+        .unwrap()
+        .is_valid());
+    }
+}
@@ -0,0 +1,47 @@
+//! The outcome of verifying proofs.
+
+/// The outcome of a `verify_*` function whose inputs were well-formed.
+///
+/// Whether the proofs verified has to be read with [`is_valid`](Self::is_valid): the result does
+/// not convert to `bool`, and ignoring it is a warning. For example, this does not compile:
+///
+/// ```compile_fail
+/// # #![deny(unused_must_use)]
+/// # use c_kzg::{Blob, KzgCommitment, KzgProof, KzgSettings};
+/// # fn check(settings: &KzgSettings, blob: &Blob, commitment: &KzgCommitment, proof: &KzgProof) {
+/// settings.verify_blob_kzg_proof(blob, commitment, proof).unwrap();
+/// # }
+/// ```
+///
+/// Code that used the `bool` these functions returned before can call `is_valid` on the result:
+///
+/// ```
+/// # use c_kzg::{Blob, Error, KzgCommitment, KzgProof, KzgSettings};
+/// # fn check(
+/// #     settings: &KzgSettings,
+/// #     blob: &Blob,
+/// #     commitment: &KzgCommitment,
+/// #     proof: &KzgProof,
+/// # ) -> Result<(), Error> {
+/// if !settings.verify_blob_kzg_proof(blob, commitment, proof)?.is_valid() {
+///     // Reject the blob.
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "the proofs are only verified if `is_valid` returns true"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VerificationResult(bool);
+
+impl VerificationResult {
+    /// Returns whether the proofs verified.
+    pub const fn is_valid(self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for VerificationResult {
+    fn from(valid: bool) -> Self {
+        Self(valid)
+    }
+}
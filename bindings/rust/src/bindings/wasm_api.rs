@@ -0,0 +1,123 @@
+//! JavaScript bindings built with `wasm-bindgen`.
+//!
+//! Byte inputs and outputs are `Uint8Array`s and errors are thrown as JS exceptions. Batch inputs
+//! are arrays of `Uint8Array`s. The names follow the `c-kzg` npm package.
+//!
+//! Only `wasm32` targets are supported. On other targets the module compiles, but every
+//! `js_sys` call panics, so the wrappers are not tested natively. The C library needs a hosted C
+//! environment (`malloc`, `free` and `memcpy`), which `wasm32-unknown-unknown` does not provide.
+//! Building for it therefore needs a clang that targets wasm32 and a libc sysroot such as
+//! wasi-libc, passed to the build with `CC_wasm32_unknown_unknown` and
+//! `CFLAGS_wasm32_unknown_unknown`.
+
+use super::{Blob, Bytes32, Error, KZGCommitment, KZGProof, KZGSettings};
+use alloc::vec::Vec;
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Copies each `Uint8Array` of `array` and converts it with `f`.
+fn from_array<T>(array: &Array, f: impl Fn(&[u8]) -> Result<T, Error>) -> Result<Vec<T>, JsError> {
+    array
+        .iter()
+        .map(|value| {
+            let bytes = value
+                .dyn_into::<Uint8Array>()
+                .map_err(|_| JsError::new("Expected an array of Uint8Array"))?;
+            Ok(f(&bytes.to_vec())?)
+        })
+        .collect()
+}
+
+/// A loaded trusted setup.
+#[wasm_bindgen(js_name = KzgSettings)]
+pub struct WasmKzgSettings(KZGSettings);
+
+#[wasm_bindgen(js_class = KzgSettings)]
+impl WasmKzgSettings {
+    /// Loads a trusted setup in the text format of `trusted_setup.txt`.
+    pub async fn load(setup_bytes: Uint8Array) -> Result<WasmKzgSettings, JsError> {
+        Ok(Self(KZGSettings::from_bytes(&setup_bytes.to_vec(), 0)?))
+    }
+
+    #[wasm_bindgen(js_name = blobToKzgCommitment)]
+    pub fn blob_to_kzg_commitment(&self, blob: &Uint8Array) -> Result<Uint8Array, JsError> {
+        let blob = Blob::from_bytes(&blob.to_vec())?;
+        let commitment = self.0.blob_to_kzg_commitment(&blob)?;
+        Ok(Uint8Array::from(&commitment[..]))
+    }
+
+    /// Returns the proof and the evaluation `y` as a two element array.
+    #[wasm_bindgen(js_name = computeKzgProof)]
+    pub fn compute_kzg_proof(&self, blob: &Uint8Array, z: &Uint8Array) -> Result<Array, JsError> {
+        let blob = Blob::from_bytes(&blob.to_vec())?;
+        let z = Bytes32::from_bytes(&z.to_vec())?;
+        let proof = self.0.compute_kzg_proof(&blob, &z)?;
+        Ok(Array::of2(
+            &Uint8Array::from(&proof.proof[..]),
+            &Uint8Array::from(&proof.y[..]),
+        ))
+    }
+
+    #[wasm_bindgen(js_name = computeBlobKzgProof)]
+    pub fn compute_blob_kzg_proof(
+        &self,
+        blob: &Uint8Array,
+        commitment: &Uint8Array,
+    ) -> Result<Uint8Array, JsError> {
+        let blob = Blob::from_bytes(&blob.to_vec())?;
+        let commitment = KZGCommitment::from_bytes(&commitment.to_vec())?;
+        let proof = self.0.compute_blob_kzg_proof(&blob, &commitment)?;
+        Ok(Uint8Array::from(&proof[..]))
+    }
+
+    #[wasm_bindgen(js_name = verifyKzgProof)]
+    pub fn verify_kzg_proof(
+        &self,
+        commitment: &Uint8Array,
+        z: &Uint8Array,
+        y: &Uint8Array,
+        proof: &Uint8Array,
+    ) -> Result<bool, JsError> {
+        let commitment = KZGCommitment::from_bytes(&commitment.to_vec())?;
+        let z = Bytes32::from_bytes(&z.to_vec())?;
+        let y = Bytes32::from_bytes(&y.to_vec())?;
+        let proof = KZGProof::from_bytes(&proof.to_vec())?;
+        Ok(self
+            .0
+            .verify_kzg_proof(&commitment, &z, &y, &proof)?
+            .is_valid())
+    }
+
+    #[wasm_bindgen(js_name = verifyBlobKzgProof)]
+    pub fn verify_blob_kzg_proof(
+        &self,
+        blob: &Uint8Array,
+        commitment: &Uint8Array,
+        proof: &Uint8Array,
+    ) -> Result<bool, JsError> {
+        let blob = Blob::from_bytes(&blob.to_vec())?;
+        let commitment = KZGCommitment::from_bytes(&commitment.to_vec())?;
+        let proof = KZGProof::from_bytes(&proof.to_vec())?;
+        Ok(self
+            .0
+            .verify_blob_kzg_proof(&blob, &commitment, &proof)?
+            .is_valid())
+    }
+
+    #[wasm_bindgen(js_name = verifyBlobKzgProofBatch)]
+    pub fn verify_blob_kzg_proof_batch(
+        &self,
+        blobs: &Array,
+        commitments: &Array,
+        proofs: &Array,
+    ) -> Result<bool, JsError> {
+        let blobs = from_array(blobs, Blob::from_bytes)?;
+        let commitments = from_array(commitments, KZGCommitment::from_bytes)?;
+        let proofs = from_array(proofs, KZGProof::from_bytes)?;
+        Ok(self
+            .0
+            .verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)?
+            .is_valid())
+    }
+}
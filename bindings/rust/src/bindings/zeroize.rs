@@ -0,0 +1,145 @@
+//! Wiping of the trusted setup before it is freed, and of blob and cell data.
+//!
+//! With the `zeroize` feature, dropping a [`KZGSettings`] overwrites every C allocation it owns
+//! before handing it back to the allocator:
+//!
+//! - the roots of unity (`roots_of_unity`, `brp_roots_of_unity`, `reverse_roots_of_unity`),
+//! - the G1 and G2 points (`g1_values_monomial`, `g1_values_lagrange_brp`, `g2_values_monomial`),
+//! - the FK20 columns (`x_ext_fft_columns`) and, if precompute was enabled, the fixed-base MSM
+//!   tables (`tables`).
+//!
+//! The raw point bytes decoded by [`KZGSettings::parse_kzg_trusted_setup`] are also wiped once
+//! the setup has been loaded from them. Not covered are buffers owned by the caller (e.g. the
+//! slices passed to [`KZGSettings::load_trusted_setup`]), the buffers which the C library
+//! allocates and frees internally while reading a setup file, and the scratch space used during
+//! individual operations.
+//!
+//! [`Blob`] and [`Cell`] implement [`Zeroize`], and blobs are also wiped when dropped. Cells are
+//! `Copy`, so they cannot be wiped on drop and have to be zeroized explicitly. Commitments and
+//! proofs are public values and are not wiped.
+
+use super::{
+    blst_fr, blst_p1, blst_p2, Blob, Cell, KZGSettings, CELLS_PER_EXT_BLOB,
+    FIELD_ELEMENTS_PER_CELL, FIELD_ELEMENTS_PER_EXT_BLOB, NUM_G1_POINTS, NUM_G2_POINTS,
+};
+use core::mem::size_of;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Overwrites `len` values starting at `ptr` with zeroes. Does nothing if `ptr` is null.
+///
+/// # Safety
+///
+/// If `ptr` is not null, it must be valid for writes of `len` values of `T`.
+unsafe fn zeroize_raw<T>(ptr: *mut T, len: usize) {
+    if !ptr.is_null() {
+        core::slice::from_raw_parts_mut(ptr.cast::<u8>(), len * size_of::<T>()).zeroize();
+    }
+}
+
+impl KZGSettings {
+    /// Overwrites all of the C allocations owned by these settings with zeroes.
+    ///
+    /// The settings must not be used for anything other than freeing afterwards.
+    pub(crate) fn zeroize_tables(&mut self) {
+        // SAFETY: the array sizes match the allocations made in `load_trusted_setup`. Null
+        // pointers, which the C library leaves behind on failure, are skipped.
+        unsafe {
+            zeroize_raw::<blst_fr>(self.roots_of_unity, FIELD_ELEMENTS_PER_EXT_BLOB + 1);
+            zeroize_raw::<blst_fr>(self.brp_roots_of_unity, FIELD_ELEMENTS_PER_EXT_BLOB);
+            zeroize_raw::<blst_fr>(self.reverse_roots_of_unity, FIELD_ELEMENTS_PER_EXT_BLOB + 1);
+            zeroize_raw::<blst_p1>(self.g1_values_monomial, NUM_G1_POINTS);
+            zeroize_raw::<blst_p1>(self.g1_values_lagrange_brp, NUM_G1_POINTS);
+            zeroize_raw::<blst_p2>(self.g2_values_monomial, NUM_G2_POINTS);
+
+            if !self.x_ext_fft_columns.is_null() {
+                for i in 0..CELLS_PER_EXT_BLOB {
+                    let column = *self.x_ext_fft_columns.add(i);
+                    zeroize_raw::<blst_p1>(column, FIELD_ELEMENTS_PER_CELL);
+                }
+            }
+
+            // The pointer arrays themselves are kept intact so that they can still be freed.
+            if !self.tables.is_null() {
+                let table_size = blst::blst_p1s_mult_wbits_precompute_sizeof(
+                    self.wbits,
+                    FIELD_ELEMENTS_PER_CELL,
+                );
+                for i in 0..CELLS_PER_EXT_BLOB {
+                    let table = *self.tables.add(i);
+                    zeroize_raw::<u8>(table.cast(), table_size);
+                }
+            }
+        }
+    }
+}
+
+impl ZeroizeOnDrop for KZGSettings {}
+
+impl Zeroize for Blob {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl Drop for Blob {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Blob {}
+
+impl Zeroize for Cell {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use ::zeroize::Zeroize;
+
+    #[test]
+    fn test_api_unchanged_with_zeroize() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(trusted_setup_file.exists());
+        let blob = Blob::new([0; BYTES_PER_BLOB]);
+
+        // Load, use, and drop the settings a few times, with and without precomputed tables.
+        let mut commitments = Vec::new();
+        for precompute in [0, 1, 0] {
+            let kzg_settings =
+                KZGSettings::load_trusted_setup_file(trusted_setup_file, precompute).unwrap();
+            let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+            let proof = kzg_settings
+                .compute_blob_kzg_proof(&blob, &commitment)
+                .unwrap();
+            assert!(kzg_settings
+                .verify_blob_kzg_proof(&blob, &commitment, &proof)
+                .unwrap()
+                .is_valid());
+            commitments.push(commitment);
+        }
+        assert!(commitments.windows(2).all(|w| w[0] == w[1]));
+
+        // The text parser wipes its buffers and still produces working settings.
+        let trusted_setup = std::fs::read_to_string(trusted_setup_file).unwrap();
+        let kzg_settings = KZGSettings::parse_kzg_trusted_setup(&trusted_setup, 0).unwrap();
+        assert_eq!(
+            kzg_settings.blob_to_kzg_commitment(&blob).unwrap(),
+            commitments[0]
+        );
+    }
+
+    #[test]
+    fn test_zeroize_blob_and_cell() {
+        let mut blob = Blob::new([1; BYTES_PER_BLOB]);
+        blob.zeroize();
+        assert_eq!(blob.as_ref(), &[0; BYTES_PER_BLOB][..]);
+
+        let mut cell = Cell::new([1; BYTES_PER_CELL]);
+        cell.zeroize();
+        assert_eq!(cell.as_ref(), &[0; BYTES_PER_CELL][..]);
+    }
+}
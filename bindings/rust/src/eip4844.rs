@@ -0,0 +1,46 @@
+//! Helpers defined by EIP-4844 on top of the KZG functions.
+
+use crate::KzgCommitment;
+use sha2::{Digest, Sha256};
+
+/// The version byte of versioned hashes of KZG commitments.
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+/// Returns the versioned hash of `commitment`, which is its SHA-256 hash with the first byte
+/// replaced by [`VERSIONED_HASH_VERSION_KZG`].
+pub fn compute_versioned_hash(commitment: &KzgCommitment) -> [u8; 32] {
+    let mut hash: [u8; 32] = Sha256::digest(&commitment[..]).into();
+    hash[0] = VERSIONED_HASH_VERSION_KZG;
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Blob, KzgSettings, BYTES_PER_BLOB};
+    use std::path::Path;
+
+    #[test]
+    fn test_compute_versioned_hash() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        let kzg_settings = KzgSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        // The commitment to the empty blob is the point at infinity.
+        let commitment = kzg_settings
+            .blob_to_kzg_commitment(&Blob::new([0; BYTES_PER_BLOB]))
+            .unwrap();
+        assert_eq!(
+            hex::encode(compute_versioned_hash(&commitment)),
+            "010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014"
+        );
+
+        // The generator of G1.
+        let commitment = KzgCommitment::from_hex(
+            "0x97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+        )
+        .unwrap();
+        assert_eq!(
+            hex::encode(compute_versioned_hash(&commitment)),
+            "01cf478a431837728dcec3461f4f53b8749cdc4e03496dcaed459dea82b82eb8"
+        );
+    }
+}
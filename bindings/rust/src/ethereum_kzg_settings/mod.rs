@@ -1,5 +1,7 @@
-use crate::KzgSettings;
+use crate::{KzgSettings, Precompute};
+#[cfg(feature = "ethereum_kzg_settings")]
 use alloc::{boxed::Box, sync::Arc};
+#[cfg(feature = "ethereum_kzg_settings")]
 use once_cell::race::OnceBox;
 
 /// Default G1 Lagrange bytes.
@@ -9,30 +11,43 @@ const ETH_G1_LAGRANGE_POINTS: &[u8] = include_bytes!("./g1_lagrange_bytes.bin");
 /// Default G2 monomial bytes.
 const ETH_G2_MONOMIAL_POINTS: &[u8] = include_bytes!("./g2_monomial_bytes.bin");
 
+impl KzgSettings {
+    /// Loads the Ethereum mainnet trusted setup embedded in the binary.
+    ///
+    /// This does not access the filesystem. Each call loads a fresh copy of the settings; use
+    /// `ethereum_kzg_settings` to share a single lazily loaded instance instead.
+    pub fn load_default(precompute: impl Into<Precompute>) -> Result<Self, crate::Error> {
+        Self::load_trusted_setup(
+            ETH_G1_MONOMIAL_POINTS,
+            ETH_G1_LAGRANGE_POINTS,
+            ETH_G2_MONOMIAL_POINTS,
+            precompute,
+        )
+    }
+}
+
 /// Returns default Ethereum mainnet KZG settings.
 ///
 /// If you need a cloneable settings use `ethereum_kzg_settings_arc` instead.
-pub fn ethereum_kzg_settings(precompute: u64) -> &'static KzgSettings {
-    ethereum_kzg_settings_inner(precompute).as_ref()
+#[cfg(feature = "ethereum_kzg_settings")]
+pub fn ethereum_kzg_settings(precompute: impl Into<Precompute>) -> &'static KzgSettings {
+    ethereum_kzg_settings_inner(precompute.into()).as_ref()
 }
 
 /// Returns default Ethereum mainnet KZG settings as an `Arc`.
 ///
 /// It is useful for sharing the settings in multiple places.
-pub fn ethereum_kzg_settings_arc(precompute: u64) -> Arc<KzgSettings> {
-    ethereum_kzg_settings_inner(precompute).clone()
+#[cfg(feature = "ethereum_kzg_settings")]
+pub fn ethereum_kzg_settings_arc(precompute: impl Into<Precompute>) -> Arc<KzgSettings> {
+    ethereum_kzg_settings_inner(precompute.into()).clone()
 }
 
-fn ethereum_kzg_settings_inner(precompute: u64) -> &'static Arc<KzgSettings> {
+#[cfg(feature = "ethereum_kzg_settings")]
+fn ethereum_kzg_settings_inner(precompute: Precompute) -> &'static Arc<KzgSettings> {
     static DEFAULT: OnceBox<Arc<KzgSettings>> = OnceBox::new();
     DEFAULT.get_or_init(|| {
-        let settings = KzgSettings::load_trusted_setup(
-            ETH_G1_MONOMIAL_POINTS,
-            ETH_G1_LAGRANGE_POINTS,
-            ETH_G2_MONOMIAL_POINTS,
-            precompute,
-        )
-        .expect("failed to load default trusted setup");
+        let settings =
+            KzgSettings::load_default(precompute).expect("failed to load default trusted setup");
         Box::new(Arc::new(settings))
     })
 }
@@ -44,6 +59,20 @@ mod tests {
     use std::path::Path;
 
     #[test]
+    pub fn load_default_matches_file() {
+        let ts_settings =
+            KzgSettings::load_trusted_setup_file(Path::new("src/trusted_setup.txt"), 0).unwrap();
+        let default_settings = KzgSettings::load_default(0).unwrap();
+        let blob = Blob::new([1u8; BYTES_PER_BLOB]);
+
+        assert_eq!(
+            ts_settings.blob_to_kzg_commitment(&blob).unwrap(),
+            default_settings.blob_to_kzg_commitment(&blob).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ethereum_kzg_settings")]
     pub fn compare_default_with_file() {
         let precompute = 0;
         let ts_settings =
@@ -53,25 +82,17 @@ mod tests {
         let blob = Blob::new([1u8; BYTES_PER_BLOB]);
 
         // generate commitment
-        let ts_commitment = ts_settings
-            .blob_to_kzg_commitment(&blob)
-            .unwrap()
-            .to_bytes();
-        let eth_commitment = eth_settings
-            .blob_to_kzg_commitment(&blob)
-            .unwrap()
-            .to_bytes();
+        let ts_commitment = ts_settings.blob_to_kzg_commitment(&blob).unwrap();
+        let eth_commitment = eth_settings.blob_to_kzg_commitment(&blob).unwrap();
         assert_eq!(ts_commitment, eth_commitment);
 
         // generate proof
         let ts_proof = ts_settings
             .compute_blob_kzg_proof(&blob, &ts_commitment)
-            .unwrap()
-            .to_bytes();
+            .unwrap();
         let eth_proof = eth_settings
             .compute_blob_kzg_proof(&blob, &eth_commitment)
-            .unwrap()
-            .to_bytes();
+            .unwrap();
         assert_eq!(ts_proof, eth_proof);
     }
 }
@@ -0,0 +1,64 @@
+//! A process-wide trusted setup, for applications that only ever load one.
+
+use crate::{Error, KzgSettings, Precompute};
+use alloc::boxed::Box;
+use once_cell::race::OnceBox;
+use std::path::Path;
+
+static GLOBAL_SETTINGS: OnceBox<KzgSettings> = OnceBox::new();
+
+/// Loads the trusted setup file at `path` as the global settings.
+///
+/// Fails if the file cannot be loaded or if the global settings were already initialized, in
+/// which case they are left unchanged.
+pub fn init_global_settings(path: &Path, precompute: impl Into<Precompute>) -> Result<(), Error> {
+    if GLOBAL_SETTINGS.get().is_some() {
+        return Err(Error::GlobalSettingsAlreadyInitialized);
+    }
+    let settings = KzgSettings::load_trusted_setup_file(path, precompute)?;
+    GLOBAL_SETTINGS
+        .set(Box::new(settings))
+        .map_err(|_| Error::GlobalSettingsAlreadyInitialized)
+}
+
+/// Returns the global settings.
+///
+/// # Panics
+///
+/// Panics if [`init_global_settings`] has not succeeded yet.
+pub fn global_settings() -> &'static KzgSettings {
+    try_global_settings()
+        .expect("global KZG settings are not initialized, call init_global_settings first")
+}
+
+/// Returns the global settings, or `None` if [`init_global_settings`] has not succeeded yet.
+pub fn try_global_settings() -> Option<&'static KzgSettings> {
+    GLOBAL_SETTINGS.get()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Blob, BYTES_PER_BLOB};
+
+    #[test]
+    fn test_global_settings() {
+        let trusted_setup_file = Path::new("src/trusted_setup.txt");
+        assert!(try_global_settings().is_none());
+        assert!(init_global_settings(Path::new("does/not/exist"), 0).is_err());
+        assert!(try_global_settings().is_none());
+
+        init_global_settings(trusted_setup_file, 0).unwrap();
+        assert!(matches!(
+            init_global_settings(trusted_setup_file, 0),
+            Err(Error::GlobalSettingsAlreadyInitialized)
+        ));
+
+        let kzg_settings = KzgSettings::load_trusted_setup_file(trusted_setup_file, 0).unwrap();
+        let blob = Blob::new([0; BYTES_PER_BLOB]);
+        assert_eq!(
+            global_settings().blob_to_kzg_commitment(&blob).unwrap(),
+            kzg_settings.blob_to_kzg_commitment(&blob).unwrap()
+        );
+    }
+}
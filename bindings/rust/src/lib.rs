@@ -10,9 +10,29 @@ extern crate blst;
 
 mod bindings;
 
-#[cfg(feature = "ethereum_kzg_settings")]
+#[cfg(feature = "default-setup")]
 mod ethereum_kzg_settings;
 
+#[cfg(feature = "sha2")]
+pub mod eip4844;
+
+#[cfg(feature = "global-settings")]
+pub mod global_settings;
+
+#[cfg(feature = "proptest")]
+pub mod testing;
+
+/// The raw FFI declarations of the C library and everything else in the internal bindings
+/// module, as an escape hatch for C functions which are not wrapped yet.
+///
+/// This module is unstable and exempt from semver: items may change or disappear in any
+/// release. Calling the C functions is unsafe, and the caller must uphold the requirements
+/// documented in the C sources, e.g. the lengths of the arrays behind the pointers.
+#[cfg(feature = "unsafe-raw-bindings")]
+pub mod raw {
+    pub use crate::bindings::*;
+}
+
 // Expose relevant types with idiomatic names.
 pub use bindings::{
     KZGCommitment as KzgCommitment, KZGProof as KzgProof, KZGSettings as KzgSettings,
@@ -26,7 +46,46 @@ pub use ethereum_kzg_settings::{ethereum_kzg_settings, ethereum_kzg_settings_arc
 // Expose the constants.
 pub use bindings::{
     BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
-    CELLS_PER_EXT_BLOB, FIELD_ELEMENTS_PER_BLOB, FIELD_ELEMENTS_PER_CELL,
+    CELLS_PER_EXT_BLOB, FIELD_ELEMENTS_PER_BLOB, FIELD_ELEMENTS_PER_CELL, MAX_BLOBS_PER_BLOCK,
+    MAX_PACKED_BYTES,
 };
 // Expose the remaining relevant types.
-pub use bindings::{Blob, Bytes32, Bytes48, Cell, Error};
+pub use bindings::{
+    bls_modulus, enumerate_cells_and_proofs, is_valid_field_element, pack_to_blob,
+    try_collect_blobs, unpack_from_blob, validate_blob_field_elements, Blob, BlobBatch,
+    BlobChunker, Bytes32, Bytes48, Cell, CellArray, CellIndex, CellProofAccumulator,
+    CommitmentBuilder, Error, FixedBytes, KzgProofWithEval, KzgSettingsBuilder, Precompute,
+    SharedKzgSettings, TrustedSetupFormat, VerificationResult,
+};
+
+// Expose the test helpers.
+#[cfg(feature = "test-utils")]
+pub use bindings::{sample_blob, sample_commitment_and_proof};
+
+// Expose the async API.
+#[cfg(feature = "tokio")]
+pub use bindings::{
+    blob_to_kzg_commitment_async, compute_blob_kzg_proof_async, compute_cells_and_kzg_proofs_async,
+    compute_kzg_proof_async, recover_cells_and_kzg_proofs_async, verify_blob_kzg_proof_async,
+    verify_blob_kzg_proof_batch_async, verify_cell_kzg_proof_batch_async, verify_kzg_proof_async,
+};
+
+// Expose the pool of trusted setup copies.
+#[cfg(feature = "std")]
+pub use bindings::{KzgSettingsPool, PoolGuard};
+
+// Expose the parallel batch threshold.
+#[cfg(feature = "rayon")]
+pub use bindings::PARALLEL_BATCH_THRESHOLD;
+
+// Expose the length of the uncompressed G1 points used by `blst`.
+#[cfg(feature = "blst-interop")]
+pub use bindings::BYTES_PER_G1_UNCOMPRESSED;
+
+// Expose the hash of the mainnet trusted setup file.
+#[cfg(feature = "sha2")]
+pub use bindings::MAINNET_TRUSTED_SETUP_SHA256;
+
+// Expose the JavaScript bindings.
+#[cfg(feature = "wasm")]
+pub use bindings::WasmKzgSettings;
@@ -0,0 +1,89 @@
+//! [`proptest`] strategies for generating valid inputs to the KZG functions.
+//!
+//! These are meant for property and differential tests, which otherwise have to make sure that
+//! every generated field element is a canonical BLS scalar.
+
+use crate::{
+    Blob, Bytes32, CellIndex, BYTES_PER_FIELD_ELEMENT, CELLS_PER_EXT_BLOB, FIELD_ELEMENTS_PER_BLOB,
+};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use proptest::collection::{btree_set, vec, SizeRange};
+use proptest::prelude::*;
+
+/// Reduces 32 big-endian bytes modulo the BLS12-381 scalar field modulus.
+fn reduce(bytes: [u8; BYTES_PER_FIELD_ELEMENT]) -> [u8; BYTES_PER_FIELD_ELEMENT] {
+    let mut scalar = blst::blst_scalar::default();
+    let mut out = [0u8; BYTES_PER_FIELD_ELEMENT];
+    // SAFETY: both buffers are `BYTES_PER_FIELD_ELEMENT` bytes long.
+    unsafe {
+        blst::blst_scalar_from_be_bytes(&mut scalar, bytes.as_ptr(), bytes.len());
+        blst::blst_bendian_from_scalar(out.as_mut_ptr(), &scalar);
+    }
+    out
+}
+
+/// Generates canonical field elements, encoded as big-endian bytes.
+pub fn field_element() -> impl Strategy<Value = Bytes32> {
+    any::<[u8; BYTES_PER_FIELD_ELEMENT]>().prop_map(|bytes| Bytes32::new(reduce(bytes)))
+}
+
+/// Generates blobs in which every field element is canonical.
+///
+/// The blobs are boxed, since proptest moves values around by copy and a few unboxed blobs are
+/// enough to overflow the stack of a test thread.
+pub fn blob() -> impl Strategy<Value = Box<Blob>> {
+    vec(field_element(), FIELD_ELEMENTS_PER_BLOB).prop_map(|elements| {
+        let bytes: Vec<u8> = elements.iter().flat_map(|element| **element).collect();
+        Box::new(Blob::from_bytes(&bytes).unwrap())
+    })
+}
+
+/// Generates sorted, deduplicated cell indices whose number is within `size`.
+pub fn cell_indices(size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<CellIndex>> {
+    btree_set(0..CELLS_PER_EXT_BLOB as u64, size).prop_map(|indices| {
+        indices
+            .into_iter()
+            .map(|index| CellIndex::new(index).unwrap())
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KzgProofWithEval, KzgSettings};
+    use proptest::test_runner::Config;
+    use std::path::Path;
+
+    #[test]
+    fn test_blob_proof_roundtrip() {
+        let kzg_settings =
+            KzgSettings::load_trusted_setup_file(Path::new("src/trusted_setup.txt"), 0).unwrap();
+
+        proptest!(Config::with_cases(4), |(blob in blob(), z in field_element())| {
+            let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+            let proof = kzg_settings
+                .compute_blob_kzg_proof(&blob, &commitment)
+                .unwrap();
+            prop_assert!(kzg_settings
+                .verify_blob_kzg_proof(&blob, &commitment, &proof)
+                .unwrap()
+                .is_valid());
+
+            let KzgProofWithEval { proof, y } = kzg_settings.compute_kzg_proof(&blob, &z).unwrap();
+            prop_assert!(kzg_settings
+                .verify_kzg_proof(&commitment, &z, &y, &proof)
+                .unwrap()
+                .is_valid());
+        });
+    }
+
+    #[test]
+    fn test_cell_indices_are_valid() {
+        proptest!(|(indices in cell_indices(0..=CELLS_PER_EXT_BLOB))| {
+            prop_assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+            prop_assert!(indices.iter().all(|index| index.as_u64() < CELLS_PER_EXT_BLOB as u64));
+        });
+    }
+}
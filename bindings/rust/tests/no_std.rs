@@ -0,0 +1,53 @@
+//! Loads a trusted setup from static bytes using only the API which is available without the
+//! `std` feature. CI runs this with `--no-default-features`.
+
+use c_kzg::*;
+
+const TRUSTED_SETUP: &[u8] = include_bytes!("../../../src/trusted_setup.txt");
+
+/// Splits the text format into the flat G1 monomial, G1 Lagrange and G2 monomial bytes.
+fn trusted_setup_points() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+    let text = core::str::from_utf8(TRUSTED_SETUP).unwrap();
+    let mut lines = text.lines();
+    let mut count = || lines.next().unwrap().parse::<usize>().unwrap();
+    let (num_g1_points, num_g2_points) = (count(), count());
+    let mut decode = |count: usize| -> Vec<u8> {
+        lines
+            .by_ref()
+            .take(count)
+            .flat_map(|line| hex::decode(line).unwrap())
+            .collect()
+    };
+    let g1_lagrange = decode(num_g1_points);
+    let g2_monomial = decode(num_g2_points);
+    let g1_monomial = decode(num_g1_points);
+    (g1_monomial, g1_lagrange, g2_monomial)
+}
+
+#[test]
+fn test_load_trusted_setup_from_static_bytes() {
+    let (g1_monomial, g1_lagrange, g2_monomial) = trusted_setup_points();
+    assert_eq!(
+        g1_monomial.len(),
+        FIELD_ELEMENTS_PER_BLOB * BYTES_PER_COMMITMENT
+    );
+    let kzg_settings =
+        KzgSettings::load_trusted_setup(&g1_monomial, &g1_lagrange, &g2_monomial, 0).unwrap();
+    let text_settings = KzgSettings::from_bytes(TRUSTED_SETUP, 0).unwrap();
+
+    let mut bytes = [0u8; BYTES_PER_BLOB];
+    bytes[1..32].copy_from_slice(&[7; 31]);
+    let blob = Blob::new(bytes);
+    let commitment = kzg_settings.blob_to_kzg_commitment(&blob).unwrap();
+    assert_eq!(
+        text_settings.blob_to_kzg_commitment(&blob).unwrap(),
+        commitment
+    );
+    let proof = kzg_settings
+        .compute_blob_kzg_proof(&blob, &commitment)
+        .unwrap();
+    assert!(kzg_settings
+        .verify_blob_kzg_proof(&blob, &commitment, &proof)
+        .unwrap()
+        .is_valid());
+}
@@ -0,0 +1,87 @@
+//! Properties of the public API that must hold for arbitrary inputs.
+
+use c_kzg::testing::{blob, cell_indices, field_element};
+use c_kzg::*;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::Config;
+
+fn kzg_settings() -> &'static KzgSettings {
+    ethereum_kzg_settings(0)
+}
+
+/// Generates commitments and proofs which are usually not valid points.
+fn bytes48() -> impl Strategy<Value = Bytes48> {
+    any::<[u8; BYTES_PER_COMMITMENT]>().prop_map(Bytes48::new)
+}
+
+proptest! {
+    #![proptest_config(Config::with_cases(4))]
+
+    #[test]
+    fn prop_kzg_proof_verifies(blob in blob(), z in field_element()) {
+        let commitment = kzg_settings().blob_to_kzg_commitment(&blob).unwrap();
+        let KzgProofWithEval { proof, y } = kzg_settings().compute_kzg_proof(&blob, &z).unwrap();
+        prop_assert!(kzg_settings().verify_kzg_proof(&commitment, &z, &y, &proof).unwrap().is_valid());
+
+        let proof = kzg_settings().compute_blob_kzg_proof(&blob, &commitment).unwrap();
+        prop_assert!(kzg_settings().verify_blob_kzg_proof(&blob, &commitment, &proof).unwrap().is_valid());
+    }
+}
+
+proptest! {
+    #![proptest_config(Config::with_cases(2))]
+
+    #[test]
+    fn prop_recovered_cells_verify(
+        blob in blob(),
+        indices in cell_indices(CELLS_PER_EXT_BLOB / 2..=CELLS_PER_EXT_BLOB),
+    ) {
+        let commitment = kzg_settings().blob_to_kzg_commitment(&blob).unwrap();
+        let (cells, proofs) = kzg_settings().compute_cells_and_kzg_proofs(&blob).unwrap();
+        let known_cells: Vec<Cell> = indices
+            .iter()
+            .map(|index| cells[index.as_u64() as usize])
+            .collect();
+
+        let (recovered_cells, recovered_proofs) = kzg_settings()
+            .recover_cells_and_kzg_proofs(&indices, &known_cells)
+            .unwrap();
+        prop_assert_eq!(&recovered_cells, &cells);
+        prop_assert_eq!(&recovered_proofs, &proofs);
+
+        let all_indices: Vec<CellIndex> = (0..CELLS_PER_EXT_BLOB as u64)
+            .map(|index| CellIndex::new(index).unwrap())
+            .collect();
+        prop_assert!(kzg_settings()
+            .verify_cell_kzg_proof_batch(
+                &[commitment; CELLS_PER_EXT_BLOB],
+                &all_indices,
+                &recovered_cells[..],
+                &recovered_proofs[..],
+            )
+            .unwrap()
+            .is_valid());
+    }
+}
+
+proptest! {
+    #![proptest_config(Config::with_cases(32))]
+
+    #[test]
+    fn prop_arbitrary_bytes_do_not_panic(
+        blob_bytes in vec(any::<u8>(), BYTES_PER_BLOB - 1..=BYTES_PER_BLOB + 1),
+        z in any::<[u8; BYTES_PER_FIELD_ELEMENT]>(),
+        y in any::<[u8; BYTES_PER_FIELD_ELEMENT]>(),
+        commitment in bytes48(),
+        proof in bytes48(),
+    ) {
+        let (z, y) = (Bytes32::new(z), Bytes32::new(y));
+        let (commitment, proof) = (KzgCommitment::from(commitment), KzgProof::from(proof));
+        let _ = kzg_settings().verify_kzg_proof(&commitment, &z, &y, &proof);
+        if let Ok(blob) = Blob::from_bytes(&blob_bytes) {
+            let _ = kzg_settings().verify_blob_kzg_proof(&blob, &commitment, &proof);
+            let _ = kzg_settings().compute_kzg_proof(&blob, &z);
+        }
+    }
+}
@@ -62,7 +62,7 @@ fn initialize_constantine_ctx() -> Arc<SafeEthKzgContext> {
 #[derive(Arbitrary, Debug)]
 struct Input {
     blob: c_kzg::Blob,
-    commitment: c_kzg::Bytes48,
+    commitment: c_kzg::KzgCommitment,
 }
 
 fuzz_target!(|input: Input| {
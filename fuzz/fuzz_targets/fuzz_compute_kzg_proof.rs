@@ -74,10 +74,10 @@ fuzz_target!(|input: Input| {
     let cnst_result = cnst.compute_kzg_proof(&input.blob, &input.z);
 
     match (&ckzg_result, &cnst_result) {
-        (Ok((ckzg_proof, ckzg_y)), Ok((cnst_proof, cnst_y))) => {
+        (Ok(ckzg), Ok((cnst_proof, cnst_y))) => {
             // Ensure the results are the same.
-            assert_eq!(*ckzg_proof.as_slice(), *cnst_proof.as_slice());
-            assert_eq!(*ckzg_y.as_slice(), *cnst_y.as_slice());
+            assert_eq!(*ckzg.proof.as_slice(), *cnst_proof.as_slice());
+            assert_eq!(*ckzg.y.as_slice(), *cnst_y.as_slice());
         }
         (Err(_), Err(_)) => {
             // Cannot compare errors, they are unique.
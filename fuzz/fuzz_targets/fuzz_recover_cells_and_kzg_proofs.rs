@@ -6,6 +6,7 @@ extern crate core;
 
 use arbitrary::Arbitrary;
 use c_kzg::Cell;
+use c_kzg::CellIndex;
 use c_kzg::KzgSettings;
 use c_kzg::BYTES_PER_CELL;
 use lazy_static::lazy_static;
@@ -33,8 +34,9 @@ fuzz_target!(|input: Input| {
         input.cells.iter().map(Cell::to_bytes).collect();
     let cells_bytes: Vec<&[u8; BYTES_PER_CELL]> = cells_bytes_owned.iter().collect();
 
-    let ckzg_result = KZG_SETTINGS
-        .recover_cells_and_kzg_proofs(input.cell_indices.as_slice(), input.cells.as_slice());
+    let ckzg_result = CellIndex::from_slice(&input.cell_indices).and_then(|cell_indices| {
+        KZG_SETTINGS.recover_cells_and_kzg_proofs(cell_indices, input.cells.as_slice())
+    });
     let rkzg_result = DAS_CONTEXT.recover_cells_and_proofs(input.cell_indices, cells_bytes);
 
     match (&ckzg_result, &rkzg_result) {
@@ -62,8 +62,8 @@ fn initialize_constantine_ctx() -> Arc<SafeEthKzgContext> {
 #[derive(Arbitrary, Debug)]
 struct Input {
     blobs: Vec<c_kzg::Blob>,
-    commitments: Vec<c_kzg::Bytes48>,
-    proofs: Vec<c_kzg::Bytes48>,
+    commitments: Vec<c_kzg::KzgCommitment>,
+    proofs: Vec<c_kzg::KzgProof>,
     /* Only for constantine */
     secure_random_bytes: [u8; 32],
 }
@@ -76,9 +76,8 @@ fuzz_target!(|input: Input| {
     let blobs: Vec<[u8; c_kzg::BYTES_PER_BLOB]> =
         input.blobs.iter().map(|b| b.clone().into_inner()).collect();
     let commitments: Vec<[u8; c_kzg::BYTES_PER_COMMITMENT]> =
-        input.commitments.iter().map(|c| c.into_inner()).collect();
-    let proofs: Vec<[u8; c_kzg::BYTES_PER_PROOF]> =
-        input.proofs.iter().map(|p| p.into_inner()).collect();
+        input.commitments.iter().map(|c| **c).collect();
+    let proofs: Vec<[u8; c_kzg::BYTES_PER_PROOF]> = input.proofs.iter().map(|p| **p).collect();
 
     let ckzg_result =
         KZG_SETTINGS.verify_blob_kzg_proof_batch(&input.blobs, &input.commitments, &input.proofs);
@@ -91,7 +90,7 @@ fuzz_target!(|input: Input| {
 
     match (&ckzg_result, &cnst_result) {
         (Ok(ckzg_valid), Ok(cnst_valid)) => {
-            assert_eq!(*ckzg_valid, *cnst_valid);
+            assert_eq!(ckzg_valid.is_valid(), *cnst_valid);
         }
         (Err(_), Err(_)) => {
             // Cannot compare errors, they are unique.
@@ -9,7 +9,7 @@ use c_kzg::KzgSettings;
 use c_kzg::BYTES_PER_CELL;
 use c_kzg::BYTES_PER_COMMITMENT;
 use c_kzg::BYTES_PER_PROOF;
-use c_kzg::{Bytes48, Cell};
+use c_kzg::{Cell, CellIndex, KzgCommitment, KzgProof};
 use lazy_static::lazy_static;
 use libfuzzer_sys::fuzz_target;
 use rust_eth_kzg::DASContext;
@@ -26,10 +26,10 @@ lazy_static! {
 
 #[derive(Arbitrary, Debug)]
 struct Input {
-    commitments: Vec<Bytes48>,
+    commitments: Vec<KzgCommitment>,
     cell_indices: Vec<u64>,
     cells: Vec<Cell>,
-    proofs: Vec<Bytes48>,
+    proofs: Vec<KzgProof>,
 }
 
 fuzz_target!(|input: Input| {
@@ -37,26 +37,21 @@ fuzz_target!(|input: Input| {
         input.cells.iter().map(Cell::to_bytes).collect();
     let cells_bytes: Vec<&[u8; BYTES_PER_CELL]> = cells_bytes_owned.iter().collect();
 
-    let commitments_owned: Vec<[u8; BYTES_PER_COMMITMENT]> = input
-        .commitments
-        .iter()
-        .map(|c| Bytes48::into_inner(*c))
-        .collect();
+    let commitments_owned: Vec<[u8; BYTES_PER_COMMITMENT]> =
+        input.commitments.iter().map(|c| **c).collect();
     let commitments_bytes: Vec<&[u8; BYTES_PER_COMMITMENT]> = commitments_owned.iter().collect();
 
-    let proofs_owned: Vec<[u8; BYTES_PER_PROOF]> = input
-        .proofs
-        .iter()
-        .map(|p| Bytes48::into_inner(*p))
-        .collect();
+    let proofs_owned: Vec<[u8; BYTES_PER_PROOF]> = input.proofs.iter().map(|p| **p).collect();
     let proofs_bytes: Vec<&[u8; BYTES_PER_PROOF]> = proofs_owned.iter().collect();
 
-    let ckzg_result = KZG_SETTINGS.verify_cell_kzg_proof_batch(
-        &input.commitments,
-        &input.cell_indices,
-        &input.cells,
-        &input.proofs,
-    );
+    let ckzg_result = CellIndex::from_slice(&input.cell_indices).and_then(|cell_indices| {
+        KZG_SETTINGS.verify_cell_kzg_proof_batch(
+            &input.commitments,
+            cell_indices,
+            &input.cells,
+            &input.proofs,
+        )
+    });
     let rkzg_result = DAS_CONTEXT.verify_cell_kzg_proof_batch(
         commitments_bytes,
         input.cell_indices,
@@ -67,11 +62,11 @@ fuzz_target!(|input: Input| {
     match (&ckzg_result, &rkzg_result) {
         (Ok(ckzg_valid), Ok(())) => {
             // One returns a boolean, the other just says Ok.
-            assert_eq!(*ckzg_valid, true);
+            assert_eq!(ckzg_valid.is_valid(), true);
         }
         (Ok(ckzg_valid), Err(err)) => {
             // If ckzg was Ok, ensure the proof was rejected.
-            assert_eq!(*ckzg_valid, false);
+            assert_eq!(ckzg_valid.is_valid(), false);
             if !err.invalid_proof() {
                 panic!("Expected InvalidProof, got {:?}", err);
             }
@@ -61,10 +61,10 @@ fn initialize_constantine_ctx() -> Arc<SafeEthKzgContext> {
 
 #[derive(Arbitrary, Debug)]
 struct Input {
-    commitment: c_kzg::Bytes48,
+    commitment: c_kzg::KzgCommitment,
     z: c_kzg::Bytes32,
     y: c_kzg::Bytes32,
-    proof: c_kzg::Bytes48,
+    proof: c_kzg::KzgProof,
 }
 
 fuzz_target!(|input: Input| {
@@ -78,7 +78,7 @@ fuzz_target!(|input: Input| {
 
     match (&ckzg_result, &cnst_result) {
         (Ok(ckzg_valid), Ok(cnst_valid)) => {
-            assert_eq!(*ckzg_valid, *cnst_valid);
+            assert_eq!(ckzg_valid.is_valid(), *cnst_valid);
         }
         (Err(_), Err(_)) => {
             // Cannot compare errors, they are unique.
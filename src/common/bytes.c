@@ -114,6 +114,25 @@ C_KZG_RET bytes_to_kzg_proof(g1_t *out, const Bytes48 *b) {
     return validate_kzg_g1(out, b);
 }
 
+/**
+ * Convert bytes from a trusted source into a g1 point, skipping the subgroup check.
+ *
+ * @param[out]  out The output g1 point
+ * @param[in]   b   The proof/commitment bytes
+ *
+ * @remark The point is still checked to be on the curve. A point outside of the subgroup makes
+ * the functions it is passed to return meaningless results, so this must only be used for points
+ * that were already validated.
+ */
+C_KZG_RET bytes_to_g1_unchecked(g1_t *out, const Bytes48 *b) {
+    blst_p1_affine p1_affine;
+
+    if (blst_p1_uncompress(&p1_affine, b->bytes) != BLST_SUCCESS) return C_KZG_BADARGS;
+    blst_p1_from_affine(out, &p1_affine);
+
+    return C_KZG_OK;
+}
+
 /**
  * Map bytes to a BLS field element.
  *
@@ -412,6 +412,33 @@ out:
     return ret;
 }
 
+/**
+ * Evaluate the polynomial of a blob at position z, without computing a proof.
+ *
+ * @param[out]  y_out   The evaluation of the polynomial at the evaluation point z
+ * @param[in]   blob    The blob (polynomial) to evaluate
+ * @param[in]   z       The evaluation point
+ * @param[in]   s       The trusted setup
+ */
+C_KZG_RET evaluate_blob_at(
+    Bytes32 *y_out, const Blob *blob, const Bytes32 *z_bytes, const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    Polynomial polynomial;
+    fr_t frz, fry;
+
+    ret = blob_to_polynomial(polynomial.evals, blob);
+    if (ret != C_KZG_OK) goto out;
+    ret = bytes_to_bls_field(&frz, z_bytes);
+    if (ret != C_KZG_OK) goto out;
+    ret = evaluate_polynomial_in_evaluation_form(&fry, &polynomial, &frz, s);
+    if (ret != C_KZG_OK) goto out;
+    bytes_from_bls_field(y_out, &fry);
+
+out:
+    return ret;
+}
+
 /**
  * Helper function for compute_kzg_proof() and compute_blob_kzg_proof().
  *
@@ -540,19 +567,21 @@ out:
 }
 
 /**
- * Given a blob and its proof, verify that it corresponds to the provided commitment.
+ * Helper function for verify_blob_kzg_proof and verify_blob_kzg_proof_unchecked.
  *
  * @param[out]  ok                  True if the proofs are valid, otherwise false
  * @param[in]   blob                Blob to verify
  * @param[in]   commitment_bytes    Commitment to verify
  * @param[in]   proof_bytes         Proof used for verification
+ * @param[in]   subgroup_check      Whether to check that the commitment and proof are in G1
  * @param[in]   s                   The trusted setup
  */
-C_KZG_RET verify_blob_kzg_proof(
+static C_KZG_RET verify_blob_kzg_proof_impl(
     bool *ok,
     const Blob *blob,
     const Bytes48 *commitment_bytes,
     const Bytes48 *proof_bytes,
+    bool subgroup_check,
     const KZGSettings *s
 ) {
     C_KZG_RET ret;
@@ -563,11 +592,19 @@ C_KZG_RET verify_blob_kzg_proof(
     *ok = false;
 
     /* Do conversions first to fail fast, compute_challenge is expensive */
-    ret = bytes_to_kzg_commitment(&commitment_g1, commitment_bytes);
+    if (subgroup_check) {
+        ret = bytes_to_kzg_commitment(&commitment_g1, commitment_bytes);
+    } else {
+        ret = bytes_to_g1_unchecked(&commitment_g1, commitment_bytes);
+    }
     if (ret != C_KZG_OK) return ret;
     ret = blob_to_polynomial(polynomial.evals, blob);
     if (ret != C_KZG_OK) return ret;
-    ret = bytes_to_kzg_proof(&proof_g1, proof_bytes);
+    if (subgroup_check) {
+        ret = bytes_to_kzg_proof(&proof_g1, proof_bytes);
+    } else {
+        ret = bytes_to_g1_unchecked(&proof_g1, proof_bytes);
+    }
     if (ret != C_KZG_OK) return ret;
 
     /* Compute challenge for the blob/commitment */
@@ -581,6 +618,48 @@ C_KZG_RET verify_blob_kzg_proof(
     return verify_kzg_proof_impl(ok, &commitment_g1, &evaluation_challenge_fr, &y_fr, &proof_g1, s);
 }
 
+/**
+ * Given a blob and its proof, verify that it corresponds to the provided commitment.
+ *
+ * @param[out]  ok                  True if the proofs are valid, otherwise false
+ * @param[in]   blob                Blob to verify
+ * @param[in]   commitment_bytes    Commitment to verify
+ * @param[in]   proof_bytes         Proof used for verification
+ * @param[in]   s                   The trusted setup
+ */
+C_KZG_RET verify_blob_kzg_proof(
+    bool *ok,
+    const Blob *blob,
+    const Bytes48 *commitment_bytes,
+    const Bytes48 *proof_bytes,
+    const KZGSettings *s
+) {
+    return verify_blob_kzg_proof_impl(ok, blob, commitment_bytes, proof_bytes, true, s);
+}
+
+/**
+ * Same as verify_blob_kzg_proof, but without checking that the commitment and proof are in G1.
+ *
+ * @param[out]  ok                  True if the proofs are valid, otherwise false
+ * @param[in]   blob                Blob to verify
+ * @param[in]   commitment_bytes    Commitment to verify
+ * @param[in]   proof_bytes         Proof used for verification
+ * @param[in]   s                   The trusted setup
+ *
+ * @remark This deviates from the spec and must only be used for commitments and proofs that were
+ * already validated, e.g. because they were produced locally. For points outside of G1 the result
+ * is meaningless.
+ */
+C_KZG_RET verify_blob_kzg_proof_unchecked(
+    bool *ok,
+    const Blob *blob,
+    const Bytes48 *commitment_bytes,
+    const Bytes48 *proof_bytes,
+    const KZGSettings *s
+) {
+    return verify_blob_kzg_proof_impl(ok, blob, commitment_bytes, proof_bytes, false, s);
+}
+
 /**
  * Compute random linear combination challenge scalars for batch verification.
  *
@@ -657,7 +736,8 @@ out:
 }
 
 /**
- * Helper function for verify_blob_kzg_proof_batch(): actually perform the verification.
+ * Helper function for verify_blob_kzg_proof_batch() and verify_kzg_proof_batch(): actually perform
+ * the verification.
  *
  * @param[out]  ok              True if the proofs are valid, otherwise false
  * @param[in]   commitments_g1  Array of commitments to verify
@@ -671,7 +751,7 @@ out:
  * @remark This function assumes that `n` is trusted and that all input arrays contain `n` elements.
  * `n` should be the actual size of the arrays and not read off a length field in the protocol.
  */
-static C_KZG_RET verify_kzg_proof_batch(
+static C_KZG_RET verify_kzg_proof_batch_impl(
     bool *ok,
     const g1_t *commitments_g1,
     const fr_t *zs_fr,
@@ -806,7 +886,7 @@ C_KZG_RET verify_blob_kzg_proof_batch(
         if (ret != C_KZG_OK) goto out;
     }
 
-    ret = verify_kzg_proof_batch(
+    ret = verify_kzg_proof_batch_impl(
         ok, commitments_g1, evaluation_challenges_fr, ys_fr, proofs_g1, n, s
     );
 
@@ -817,3 +897,82 @@ out:
     c_kzg_free(ys_fr);
     return ret;
 }
+
+/**
+ * Given a list of KZG proofs claiming that `p_i(z_i) == y_i`, verify all of them at once.
+ *
+ * The pairing checks are combined with a random linear combination, so this is much faster than
+ * calling verify_kzg_proof() for each proof.
+ *
+ * @param[out]  ok                  True if all of the proofs are valid, otherwise false
+ * @param[in]   commitments_bytes   Array of commitments to polynomials
+ * @param[in]   zs_bytes            Array of evaluation points
+ * @param[in]   ys_bytes            Array of claimed evaluation results
+ * @param[in]   proofs_bytes        Array of proofs used for verification
+ * @param[in]   n                   The number of commitments/zs/ys/proofs
+ * @param[in]   s                   The trusted setup
+ *
+ * @remark This function accepts if called with `n==0`.
+ * @remark This function assumes that `n` is trusted and that all input arrays contain `n` elements.
+ * `n` should be the actual size of the arrays and not read off a length field in the protocol.
+ */
+C_KZG_RET verify_kzg_proof_batch(
+    bool *ok,
+    const Bytes48 *commitments_bytes,
+    const Bytes32 *zs_bytes,
+    const Bytes32 *ys_bytes,
+    const Bytes48 *proofs_bytes,
+    uint64_t n,
+    const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    g1_t *commitments_g1 = NULL;
+    g1_t *proofs_g1 = NULL;
+    fr_t *zs_fr = NULL;
+    fr_t *ys_fr = NULL;
+
+    /* Exit early if we are given zero proofs */
+    if (n == 0) {
+        *ok = true;
+        return C_KZG_OK;
+    }
+
+    /* For a single proof, just do a regular single verification */
+    if (n == 1) {
+        return verify_kzg_proof(
+            ok, &commitments_bytes[0], &zs_bytes[0], &ys_bytes[0], &proofs_bytes[0], s
+        );
+    }
+
+    *ok = false;
+
+    ret = new_g1_array(&commitments_g1, n);
+    if (ret != C_KZG_OK) goto out;
+    ret = new_g1_array(&proofs_g1, n);
+    if (ret != C_KZG_OK) goto out;
+    ret = new_fr_array(&zs_fr, n);
+    if (ret != C_KZG_OK) goto out;
+    ret = new_fr_array(&ys_fr, n);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Convert untrusted inputs to trusted inputs */
+    for (size_t i = 0; i < n; i++) {
+        ret = bytes_to_kzg_commitment(&commitments_g1[i], &commitments_bytes[i]);
+        if (ret != C_KZG_OK) goto out;
+        ret = bytes_to_bls_field(&zs_fr[i], &zs_bytes[i]);
+        if (ret != C_KZG_OK) goto out;
+        ret = bytes_to_bls_field(&ys_fr[i], &ys_bytes[i]);
+        if (ret != C_KZG_OK) goto out;
+        ret = bytes_to_kzg_proof(&proofs_g1[i], &proofs_bytes[i]);
+        if (ret != C_KZG_OK) goto out;
+    }
+
+    ret = verify_kzg_proof_batch_impl(ok, commitments_g1, zs_fr, ys_fr, proofs_g1, n, s);
+
+out:
+    c_kzg_free(commitments_g1);
+    c_kzg_free(proofs_g1);
+    c_kzg_free(zs_fr);
+    c_kzg_free(ys_fr);
+    return ret;
+}
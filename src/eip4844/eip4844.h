@@ -48,6 +48,10 @@ C_KZG_RET compute_kzg_proof(
     const KZGSettings *s
 );
 
+C_KZG_RET evaluate_blob_at(
+    Bytes32 *y_out, const Blob *blob, const Bytes32 *z_bytes, const KZGSettings *s
+);
+
 C_KZG_RET compute_blob_kzg_proof(
     KZGProof *out, const Blob *blob, const Bytes48 *commitment_bytes, const KZGSettings *s
 );
@@ -69,6 +73,14 @@ C_KZG_RET verify_blob_kzg_proof(
     const KZGSettings *s
 );
 
+C_KZG_RET verify_blob_kzg_proof_unchecked(
+    bool *ok,
+    const Blob *blob,
+    const Bytes48 *commitment_bytes,
+    const Bytes48 *proof_bytes,
+    const KZGSettings *s
+);
+
 C_KZG_RET verify_blob_kzg_proof_batch(
     bool *ok,
     const Blob *blobs,
@@ -78,6 +90,16 @@ C_KZG_RET verify_blob_kzg_proof_batch(
     const KZGSettings *s
 );
 
+C_KZG_RET verify_kzg_proof_batch(
+    bool *ok,
+    const Bytes48 *commitments_bytes,
+    const Bytes32 *zs_bytes,
+    const Bytes32 *ys_bytes,
+    const Bytes48 *proofs_bytes,
+    uint64_t n,
+    const KZGSettings *s
+);
+
 #ifdef __cplusplus
 }
 #endif
@@ -145,6 +145,115 @@ out:
     return ret;
 }
 
+/**
+ * Given a blob, compute a single one of its cells and its proof.
+ *
+ * This is faster than computing all cells and proofs with compute_cells_and_kzg_proofs() when
+ * only a few cells are needed. Each proof is a multi-scalar multiplication over the setup, whereas
+ * FK20 amortizes its cost over all of the proofs.
+ *
+ * @param[out]  cell        The cell at cell_index
+ * @param[out]  proof       The proof for the cell at cell_index
+ * @param[in]   blob        The blob to get the cell/proof for
+ * @param[in]   cell_index  The index of the cell, less than CELLS_PER_EXT_BLOB
+ * @param[in]   s           The trusted setup
+ *
+ * @remark If cell is NULL, it won't be computed.
+ * @remark If proof is NULL, it won't be computed.
+ * @remark Will return an error if both cell & proof are NULL.
+ */
+C_KZG_RET compute_cell_and_kzg_proof(
+    Cell *cell, KZGProof *proof, const Blob *blob, uint64_t cell_index, const KZGSettings *s
+) {
+    C_KZG_RET ret;
+    fr_t *poly_monomial = NULL;
+    fr_t *quotient = NULL;
+    fr_t folded[FIELD_ELEMENTS_PER_CELL];
+    fr_t data_fr[FIELD_ELEMENTS_PER_CELL];
+    fr_t coset_shift, vanishing_constant;
+    g1_t proof_g1;
+
+    /* If both of these are null, something is wrong */
+    if (cell == NULL && proof == NULL) {
+        return C_KZG_BADARGS;
+    }
+    if (cell_index >= CELLS_PER_EXT_BLOB) {
+        return C_KZG_BADARGS;
+    }
+
+    ret = new_fr_array(&poly_monomial, FIELD_ELEMENTS_PER_BLOB);
+    if (ret != C_KZG_OK) goto out;
+    ret = blob_to_polynomial(poly_monomial, blob);
+    if (ret != C_KZG_OK) goto out;
+    ret = poly_lagrange_to_monomial(poly_monomial, poly_monomial, FIELD_ELEMENTS_PER_BLOB, s);
+    if (ret != C_KZG_OK) goto out;
+
+    /*
+     * The cell consists of the evaluations over the coset `h * <w>`, where `w` is a root of unity
+     * of order FIELD_ELEMENTS_PER_CELL, in bit-reversed order. The shift `h` is the first
+     * evaluation point of the cell.
+     */
+    coset_shift = s->brp_roots_of_unity[cell_index * FIELD_ELEMENTS_PER_CELL];
+
+    if (cell != NULL) {
+        /*
+         * Evaluating p(h * x) over <w> only depends on p(h * x) mod (x^n - 1), so fold the shifted
+         * polynomial down to FIELD_ELEMENTS_PER_CELL coefficients before the FFT.
+         */
+        fr_t factor_power = FR_ONE;
+        for (size_t i = 0; i < FIELD_ELEMENTS_PER_CELL; i++) {
+            folded[i] = FR_ZERO;
+        }
+        for (size_t i = 0; i < FIELD_ELEMENTS_PER_BLOB; i++) {
+            fr_t term;
+            size_t j = i % FIELD_ELEMENTS_PER_CELL;
+            blst_fr_mul(&term, &poly_monomial[i], &factor_power);
+            blst_fr_add(&folded[j], &folded[j], &term);
+            blst_fr_mul(&factor_power, &factor_power, &coset_shift);
+        }
+
+        ret = fr_fft(data_fr, folded, FIELD_ELEMENTS_PER_CELL, s);
+        if (ret != C_KZG_OK) goto out;
+        ret = bit_reversal_permutation(data_fr, sizeof(fr_t), FIELD_ELEMENTS_PER_CELL);
+        if (ret != C_KZG_OK) goto out;
+
+        for (size_t j = 0; j < FIELD_ELEMENTS_PER_CELL; j++) {
+            size_t offset = j * BYTES_PER_FIELD_ELEMENT;
+            bytes_from_bls_field((Bytes32 *)&cell->bytes[offset], &data_fr[j]);
+        }
+    }
+
+    if (proof != NULL) {
+        /*
+         * The proof is a commitment to the quotient of p(x) by the vanishing polynomial of the
+         * coset, x^n - h^n. The remainder of the division is the interpolation polynomial of the
+         * cell, so it does not need to be subtracted first.
+         */
+        const size_t quotient_len = FIELD_ELEMENTS_PER_BLOB - FIELD_ELEMENTS_PER_CELL;
+        fr_pow(&vanishing_constant, &coset_shift, FIELD_ELEMENTS_PER_CELL);
+
+        ret = new_fr_array(&quotient, quotient_len);
+        if (ret != C_KZG_OK) goto out;
+        for (size_t i = quotient_len; i-- > 0;) {
+            quotient[i] = poly_monomial[i + FIELD_ELEMENTS_PER_CELL];
+            if (i + FIELD_ELEMENTS_PER_CELL < quotient_len) {
+                fr_t term;
+                blst_fr_mul(&term, &quotient[i + FIELD_ELEMENTS_PER_CELL], &vanishing_constant);
+                blst_fr_add(&quotient[i], &quotient[i], &term);
+            }
+        }
+
+        ret = g1_lincomb_fast(&proof_g1, s->g1_values_monomial, quotient, quotient_len);
+        if (ret != C_KZG_OK) goto out;
+        bytes_from_g1(proof, &proof_g1);
+    }
+
+out:
+    c_kzg_free(poly_monomial);
+    c_kzg_free(quotient);
+    return ret;
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Recover
 ////////////////////////////////////////////////////////////////////////////////////////////////////
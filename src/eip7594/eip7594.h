@@ -35,6 +35,10 @@ C_KZG_RET compute_cells_and_kzg_proofs(
     Cell *cells, KZGProof *proofs, const Blob *blob, const KZGSettings *s
 );
 
+C_KZG_RET compute_cell_and_kzg_proof(
+    Cell *cell, KZGProof *proof, const Blob *blob, uint64_t cell_index, const KZGSettings *s
+);
+
 C_KZG_RET recover_cells_and_kzg_proofs(
     Cell *recovered_cells,
     KZGProof *recovered_proofs,
@@ -477,6 +477,96 @@ out_success:
     return ret;
 }
 
+/**
+ * Load trusted setup into a KZGSettings from the G1 and G2 points in monomial form.
+ *
+ * @param[out]  out                     Pointer to the stored trusted setup
+ * @param[in]   g1_monomial_bytes       Array of G1 points in monomial form
+ * @param[in]   num_g1_monomial_bytes   Number of g1 monomial bytes
+ * @param[in]   g2_monomial_bytes       Array of G2 points in monomial form
+ * @param[in]   num_g2_monomial_bytes   Number of g2 monomial bytes
+ * @param[in]   precompute              Configurable value between 0-15
+ *
+ * @remark The G1 points in Lagrange form are derived from the monomial ones with an inverse FFT.
+ * @remark See also load_trusted_setup().
+ * @remark Free afterwards use with free_trusted_setup().
+ */
+C_KZG_RET load_trusted_setup_monomial(
+    KZGSettings *out,
+    const uint8_t *g1_monomial_bytes,
+    uint64_t num_g1_monomial_bytes,
+    const uint8_t *g2_monomial_bytes,
+    uint64_t num_g2_monomial_bytes,
+    uint64_t precompute
+) {
+    C_KZG_RET ret;
+    KZGSettings roots = {0};
+    g1_t *g1_monomial = NULL;
+    g1_t *g1_lagrange = NULL;
+    uint8_t *g1_lagrange_bytes = NULL;
+
+    /* Sanity check in case this is called directly */
+    if (num_g1_monomial_bytes != NUM_G1_POINTS * BYTES_PER_G1 ||
+        num_g2_monomial_bytes != NUM_G2_POINTS * BYTES_PER_G2) {
+        ret = C_KZG_BADARGS;
+        goto out;
+    }
+
+    /* The inverse FFT only needs the roots of unity */
+    ret = new_fr_array(&roots.brp_roots_of_unity, FIELD_ELEMENTS_PER_EXT_BLOB);
+    if (ret != C_KZG_OK) goto out;
+    ret = new_fr_array(&roots.roots_of_unity, FIELD_ELEMENTS_PER_EXT_BLOB + 1);
+    if (ret != C_KZG_OK) goto out;
+    ret = new_fr_array(&roots.reverse_roots_of_unity, FIELD_ELEMENTS_PER_EXT_BLOB + 1);
+    if (ret != C_KZG_OK) goto out;
+    ret = compute_roots_of_unity(&roots);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Convert all g1 monomial bytes to g1 points */
+    ret = new_g1_array(&g1_monomial, NUM_G1_POINTS);
+    if (ret != C_KZG_OK) goto out;
+    for (size_t i = 0; i < NUM_G1_POINTS; i++) {
+        blst_p1_affine g1_affine;
+        BLST_ERROR err = blst_p1_uncompress(&g1_affine, &g1_monomial_bytes[BYTES_PER_G1 * i]);
+        if (err != BLST_SUCCESS) {
+            ret = C_KZG_BADARGS;
+            goto out;
+        }
+        blst_p1_from_affine(&g1_monomial[i], &g1_affine);
+    }
+
+    /* The Lagrange form points are the inverse FFT of the monomial form points */
+    ret = new_g1_array(&g1_lagrange, NUM_G1_POINTS);
+    if (ret != C_KZG_OK) goto out;
+    ret = g1_ifft(g1_lagrange, g1_monomial, NUM_G1_POINTS, &roots);
+    if (ret != C_KZG_OK) goto out;
+
+    /* Serialize them in natural order; load_trusted_setup() applies the bit reversal */
+    ret = c_kzg_calloc((void **)&g1_lagrange_bytes, NUM_G1_POINTS, BYTES_PER_G1);
+    if (ret != C_KZG_OK) goto out;
+    for (size_t i = 0; i < NUM_G1_POINTS; i++) {
+        blst_p1_compress(&g1_lagrange_bytes[BYTES_PER_G1 * i], &g1_lagrange[i]);
+    }
+
+    ret = load_trusted_setup(
+        out,
+        g1_monomial_bytes,
+        num_g1_monomial_bytes,
+        g1_lagrange_bytes,
+        NUM_G1_POINTS * BYTES_PER_G1,
+        g2_monomial_bytes,
+        num_g2_monomial_bytes,
+        precompute
+    );
+
+out:
+    free_trusted_setup(&roots);
+    c_kzg_free(g1_monomial);
+    c_kzg_free(g1_lagrange);
+    c_kzg_free(g1_lagrange_bytes);
+    return ret;
+}
+
 /**
  * Load trusted setup from a file.
  *
@@ -39,6 +39,15 @@ C_KZG_RET load_trusted_setup(
     uint64_t precompute
 );
 
+C_KZG_RET load_trusted_setup_monomial(
+    KZGSettings *out,
+    const uint8_t *g1_monomial_bytes,
+    uint64_t num_g1_monomial_bytes,
+    const uint8_t *g2_monomial_bytes,
+    uint64_t num_g2_monomial_bytes,
+    uint64_t precompute
+);
+
 C_KZG_RET load_trusted_setup_file(KZGSettings *out, FILE *in, uint64_t precompute);
 
 void free_trusted_setup(KZGSettings *s);
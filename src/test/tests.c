@@ -1099,6 +1099,25 @@ static void test_compute_kzg_proof__succeeds_expected_proof(void) {
     ASSERT_EQUALS(diff, 0);
 }
 
+static void test_evaluate_blob_at__matches_compute_kzg_proof(void) {
+    C_KZG_RET ret;
+    Blob blob;
+    Bytes32 z, y, expected_y;
+    KZGProof proof;
+    int diff;
+
+    get_rand_blob(&blob);
+    get_rand_field_element(&z);
+
+    ret = compute_kzg_proof(&proof, &expected_y, &blob, &z, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ret = evaluate_blob_at(&y, &blob, &z, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    diff = memcmp(y.bytes, expected_y.bytes, sizeof(Bytes32));
+    ASSERT_EQUALS(diff, 0);
+}
+
 static void test_compute_and_verify_kzg_proof__succeeds_round_trip(void) {
     C_KZG_RET ret;
     Bytes48 proof;
@@ -1461,6 +1480,53 @@ static void test_compute_and_verify_blob_kzg_proof__fails_invalid_blob(void) {
     ASSERT_EQUALS(ret, C_KZG_BADARGS);
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests for verify_blob_kzg_proof_unchecked
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+static void test_verify_blob_kzg_proof_unchecked__succeeds_round_trip(void) {
+    C_KZG_RET ret;
+    Bytes48 proof;
+    KZGCommitment c;
+    Blob blob;
+    bool ok;
+
+    /* Some preparation */
+    get_rand_blob(&blob);
+    ret = blob_to_kzg_commitment(&c, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ret = compute_blob_kzg_proof(&proof, &blob, &c, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* Finally verify the proof */
+    ret = verify_blob_kzg_proof_unchecked(&ok, &blob, &c, &proof, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ASSERT_EQUALS(ok, true);
+}
+
+static void test_verify_blob_kzg_proof_unchecked__skips_subgroup_check(void) {
+    C_KZG_RET ret;
+    Bytes48 proof;
+    KZGCommitment c;
+    Blob blob;
+    bool ok;
+
+    /* Some preparation */
+    get_rand_blob(&blob);
+    ret = blob_to_kzg_commitment(&c, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    bytes48_from_hex(
+        &proof,
+        "8123456789abcdef0123456789abcdef0123456789abcdef"
+        "0123456789abcdef0123456789abcdef0123456789abcdef"
+    );
+
+    /* The point is on the curve, so it is accepted, but the proof does not verify */
+    ret = verify_blob_kzg_proof_unchecked(&ok, &blob, &c, &proof, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ASSERT_EQUALS(ok, false);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Tests for verify_kzg_proof_batch
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1607,6 +1673,44 @@ static void test_verify_kzg_proof_batch__fails_invalid_blob(void) {
     ASSERT_EQUALS(ret, C_KZG_BADARGS);
 }
 
+static void test_verify_kzg_proof_batch__point_openings_succeed(void) {
+    C_KZG_RET ret;
+    const size_t n = 4;
+    Bytes48 commitments[n], proofs[n];
+    Bytes32 zs[n], ys[n];
+    Blob blob;
+    bool ok;
+
+    for (size_t i = 0; i < n; i++) {
+        get_rand_blob(&blob);
+        get_rand_field_element(&zs[i]);
+        ret = blob_to_kzg_commitment(&commitments[i], &blob, &s);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+        ret = compute_kzg_proof(&proofs[i], &ys[i], &blob, &zs[i], &s);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+    }
+
+    /* This should work for 0, 1 and multiple proofs */
+    for (size_t count = 0; count <= n; count++) {
+        ret = verify_kzg_proof_batch(&ok, commitments, zs, ys, proofs, count, &s);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+        ASSERT_EQUALS(ok, true);
+    }
+
+    /* Swapping two evaluations makes the batch fail */
+    Bytes32 y = ys[1];
+    ys[1] = ys[2];
+    ys[2] = y;
+    ret = verify_kzg_proof_batch(&ok, commitments, zs, ys, proofs, n, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ASSERT_EQUALS(ok, false);
+
+    /* A non-canonical evaluation point is rejected */
+    bytes32_from_hex(&zs[3], "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+    ret = verify_kzg_proof_batch(&ok, commitments, zs, ys, proofs, n, &s);
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Tests for expand_root_of_unity
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1675,6 +1779,80 @@ static void test_expand_root_of_unity__fails_wrong_root_of_unity(void) {
     ASSERT_EQUALS(ret, C_KZG_BADARGS);
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests for load_trusted_setup_monomial
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+static void get_monomial_bytes(uint8_t *g1_monomial_bytes, uint8_t *g2_monomial_bytes) {
+    for (size_t i = 0; i < NUM_G1_POINTS; i++) {
+        blst_p1_compress(&g1_monomial_bytes[BYTES_PER_G1 * i], &s.g1_values_monomial[i]);
+    }
+    for (size_t i = 0; i < NUM_G2_POINTS; i++) {
+        blst_p2_compress(&g2_monomial_bytes[BYTES_PER_G2 * i], &s.g2_values_monomial[i]);
+    }
+}
+
+static void test_load_trusted_setup_monomial__matches_lagrange_setup(void) {
+    C_KZG_RET ret;
+    KZGSettings monomial_settings;
+    uint8_t *g1_monomial_bytes = NULL, *g2_monomial_bytes = NULL;
+
+    ret = c_kzg_calloc((void **)&g1_monomial_bytes, NUM_G1_POINTS, BYTES_PER_G1);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ret = c_kzg_calloc((void **)&g2_monomial_bytes, NUM_G2_POINTS, BYTES_PER_G2);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    get_monomial_bytes(g1_monomial_bytes, g2_monomial_bytes);
+
+    ret = load_trusted_setup_monomial(
+        &monomial_settings,
+        g1_monomial_bytes,
+        NUM_G1_POINTS * BYTES_PER_G1,
+        g2_monomial_bytes,
+        NUM_G2_POINTS * BYTES_PER_G2,
+        0
+    );
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    /* The derived Lagrange points are the ones of the trusted setup file */
+    for (size_t i = 0; i < NUM_G1_POINTS; i++) {
+        ASSERT(
+            "derived Lagrange point matches",
+            blst_p1_is_equal(
+                &monomial_settings.g1_values_lagrange_brp[i], &s.g1_values_lagrange_brp[i]
+            )
+        );
+    }
+
+    free_trusted_setup(&monomial_settings);
+    c_kzg_free(g1_monomial_bytes);
+    c_kzg_free(g2_monomial_bytes);
+}
+
+static void test_load_trusted_setup_monomial__fails_wrong_length(void) {
+    C_KZG_RET ret;
+    KZGSettings monomial_settings;
+    uint8_t *g1_monomial_bytes = NULL, *g2_monomial_bytes = NULL;
+
+    ret = c_kzg_calloc((void **)&g1_monomial_bytes, NUM_G1_POINTS, BYTES_PER_G1);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    ret = c_kzg_calloc((void **)&g2_monomial_bytes, NUM_G2_POINTS, BYTES_PER_G2);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+    get_monomial_bytes(g1_monomial_bytes, g2_monomial_bytes);
+
+    ret = load_trusted_setup_monomial(
+        &monomial_settings,
+        g1_monomial_bytes,
+        (NUM_G1_POINTS - 1) * BYTES_PER_G1,
+        g2_monomial_bytes,
+        NUM_G2_POINTS * BYTES_PER_G2,
+        0
+    );
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+
+    c_kzg_free(g1_monomial_bytes);
+    c_kzg_free(g2_monomial_bytes);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Tests for reconstruction
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1838,6 +2016,46 @@ static void test_deduplicate_commitments__one_commitment(void) {
     ASSERT_EQUALS(indices[0], 0);
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests for compute_cell_and_kzg_proof
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+static void test_compute_cell_and_kzg_proof__matches_all_cells(void) {
+    C_KZG_RET ret;
+    Blob blob;
+    Cell cells[CELLS_PER_EXT_BLOB];
+    KZGProof proofs[CELLS_PER_EXT_BLOB];
+    Cell cell;
+    KZGProof proof;
+    int diff;
+
+    get_rand_blob(&blob);
+    ret = compute_cells_and_kzg_proofs(cells, proofs, &blob, &s);
+    ASSERT_EQUALS(ret, C_KZG_OK);
+
+    for (size_t i = 0; i < CELLS_PER_EXT_BLOB; i += 9) {
+        ret = compute_cell_and_kzg_proof(&cell, &proof, &blob, i, &s);
+        ASSERT_EQUALS(ret, C_KZG_OK);
+        diff = memcmp(&cells[i], &cell, sizeof(Cell));
+        ASSERT_EQUALS(diff, 0);
+        diff = memcmp(&proofs[i], &proof, sizeof(KZGProof));
+        ASSERT_EQUALS(diff, 0);
+    }
+}
+
+static void test_compute_cell_and_kzg_proof__fails_invalid_arguments(void) {
+    C_KZG_RET ret;
+    Blob blob;
+    Cell cell;
+    KZGProof proof;
+
+    get_rand_blob(&blob);
+    ret = compute_cell_and_kzg_proof(&cell, &proof, &blob, CELLS_PER_EXT_BLOB, &s);
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+    ret = compute_cell_and_kzg_proof(NULL, NULL, &blob, 0, &s);
+    ASSERT_EQUALS(ret, C_KZG_BADARGS);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Tests for recover_cells_and_kzg_proofs
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -2283,6 +2501,7 @@ int main(void) {
     RUN(test_is_power_of_two__succeeds_powers_of_two);
     RUN(test_is_power_of_two__fails_not_powers_of_two);
     RUN(test_compute_kzg_proof__succeeds_expected_proof);
+    RUN(test_evaluate_blob_at__matches_compute_kzg_proof);
     RUN(test_compute_and_verify_kzg_proof__succeeds_round_trip);
     RUN(test_compute_and_verify_kzg_proof__succeeds_within_domain);
     RUN(test_compute_and_verify_kzg_proof__fails_incorrect_proof);
@@ -2296,15 +2515,20 @@ int main(void) {
     RUN(test_compute_and_verify_blob_kzg_proof__fails_compute_commitment_not_in_g1);
     RUN(test_compute_and_verify_blob_kzg_proof__fails_verify_commitment_not_in_g1);
     RUN(test_compute_and_verify_blob_kzg_proof__fails_invalid_blob);
+    RUN(test_verify_blob_kzg_proof_unchecked__succeeds_round_trip);
+    RUN(test_verify_blob_kzg_proof_unchecked__skips_subgroup_check);
     RUN(test_verify_kzg_proof_batch__succeeds_round_trip);
     RUN(test_verify_kzg_proof_batch__fails_with_incorrect_proof);
     RUN(test_verify_kzg_proof_batch__fails_proof_not_in_g1);
     RUN(test_verify_kzg_proof_batch__fails_commitment_not_in_g1);
     RUN(test_verify_kzg_proof_batch__fails_invalid_blob);
+    RUN(test_verify_kzg_proof_batch__point_openings_succeed);
     RUN(test_expand_root_of_unity__global_matches_expected);
     RUN(test_expand_root_of_unity__succeeds_with_root);
     RUN(test_expand_root_of_unity__fails_not_root_of_unity);
     RUN(test_expand_root_of_unity__fails_wrong_root_of_unity);
+    RUN(test_load_trusted_setup_monomial__matches_lagrange_setup);
+    RUN(test_load_trusted_setup_monomial__fails_wrong_length);
     RUN(test_fft);
     RUN(test_coset_fft);
     RUN(test_deduplicate_commitments__one_duplicate);
@@ -2312,6 +2536,8 @@ int main(void) {
     RUN(test_deduplicate_commitments__all_duplicates);
     RUN(test_deduplicate_commitments__no_commitments);
     RUN(test_deduplicate_commitments__one_commitment);
+    RUN(test_compute_cell_and_kzg_proof__matches_all_cells);
+    RUN(test_compute_cell_and_kzg_proof__fails_invalid_arguments);
     RUN(test_recover_cells_and_kzg_proofs__succeeds_random_blob);
     RUN(test_compute_vanishing_polynomial_from_roots);
     RUN(test_vanishing_polynomial_for_missing_cells);